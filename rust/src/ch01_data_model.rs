@@ -4,8 +4,18 @@
 //! from Chapter 1 of Fluent Python, showcasing Rust's type system, traits, and
 //! memory safety guarantees.
 
+#[cfg(feature = "std")]
 pub mod french_deck;
+pub mod matrix;
+#[cfg(feature = "std")]
+pub mod poker;
+pub mod stats;
 pub mod vector;
 
+#[cfg(feature = "std")]
 pub use french_deck::*;
+pub use matrix::*;
+#[cfg(feature = "std")]
+pub use poker::*;
+pub use stats::*;
 pub use vector::*;
@@ -4,9 +4,41 @@
 //! to create a type-safe, memory-efficient playing card deck.
 
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error types for parsing cards from text
+#[derive(Error, Debug, PartialEq)]
+pub enum ParseError {
+    #[error("Cannot parse a card from an empty string")]
+    Empty,
+    #[error("Invalid rank: {0:?}")]
+    InvalidRank(String),
+    #[error("Invalid suit: {0:?}")]
+    InvalidSuit(String),
+    #[error("Invalid card index: {0} (expected 0-51)")]
+    InvalidIndex(u8),
+}
+
+/// Suit colors, as printed on the cards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Color {
+    Red,
+    Black,
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Red => write!(f, "Red"),
+            Color::Black => write!(f, "Black"),
+        }
+    }
+}
 
 /// Card suits with explicit ordering for comparison
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -24,6 +56,34 @@ impl Suit {
             .iter()
             .copied()
     }
+
+    /// Color of the suit: Hearts and Diamonds are red, Spades and Clubs black
+    pub fn color(self) -> Color {
+        match self {
+            Suit::Hearts | Suit::Diamonds => Color::Red,
+            Suit::Spades | Suit::Clubs => Color::Black,
+        }
+    }
+
+    /// Unicode card-suit symbol for the suit
+    pub fn symbol(self) -> char {
+        match self {
+            Suit::Spades => '♠',
+            Suit::Hearts => '♥',
+            Suit::Diamonds => '♦',
+            Suit::Clubs => '♣',
+        }
+    }
+
+    /// ASCII suit letter used in card codes: `S`, `H`, `D` or `C`
+    pub fn letter(self) -> char {
+        match self {
+            Suit::Spades => 'S',
+            Suit::Hearts => 'H',
+            Suit::Diamonds => 'D',
+            Suit::Clubs => 'C',
+        }
+    }
 }
 
 impl fmt::Display for Suit {
@@ -67,10 +127,47 @@ impl Rank {
         .copied()
     }
 
-    /// Get numeric value for the rank
+    /// Get numeric value for the rank, with Ace high (14)
     pub fn value(self) -> u8 {
         self as u8
     }
+
+    /// Get numeric value for the rank, with Ace low (1) for games like Lowball
+    pub fn value_ace_low(self) -> u8 {
+        match self {
+            Rank::Ace => 1,
+            rank => rank.value(),
+        }
+    }
+
+    /// The next higher rank, or `None` for the Ace
+    pub fn succ(self) -> Option<Rank> {
+        Rank::all().find(|rank| rank.value() == self.value() + 1)
+    }
+
+    /// The next lower rank, or `None` for the Two
+    pub fn pred(self) -> Option<Rank> {
+        Rank::all().find(|rank| rank.value() + 1 == self.value())
+    }
+
+    /// Short rank token: `2`-`10`, `J`, `Q`, `K` or `A`
+    pub fn short_name(self) -> &'static str {
+        match self {
+            Rank::Two => "2",
+            Rank::Three => "3",
+            Rank::Four => "4",
+            Rank::Five => "5",
+            Rank::Six => "6",
+            Rank::Seven => "7",
+            Rank::Eight => "8",
+            Rank::Nine => "9",
+            Rank::Ten => "10",
+            Rank::Jack => "J",
+            Rank::Queen => "Q",
+            Rank::King => "K",
+            Rank::Ace => "A",
+        }
+    }
 }
 
 impl fmt::Display for Rank {
@@ -94,17 +191,32 @@ impl fmt::Display for Rank {
 }
 
 /// A playing card with rank and suit
+///
+/// The rank and suit types default to the French `Rank` and `Suit`; other
+/// card sets plug in their own (see `Deck`).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct Card {
-    pub rank: Rank,
-    pub suit: Suit,
+pub struct Card<R = Rank, S = Suit> {
+    pub rank: R,
+    pub suit: S,
 }
 
-impl Card {
+impl<R, S> Card<R, S> {
     /// Create a new card
-    pub fn new(rank: Rank, suit: Suit) -> Self {
+    pub fn new(rank: R, suit: S) -> Self {
         Self { rank, suit }
     }
+}
+
+impl Card {
+    /// Iterate over the four cards of `rank`, in `Suit::all()` order
+    pub fn all_of_rank(rank: Rank) -> impl Iterator<Item = Card> {
+        Suit::all().map(move |suit| Card::new(rank, suit))
+    }
+
+    /// Iterate over the 13 cards of `suit`, in `Rank::all()` order
+    pub fn all_of_suit(suit: Suit) -> impl Iterator<Item = Card> {
+        Rank::all().map(move |rank| Card::new(rank, suit))
+    }
 
     /// Get the card's rank value for comparison
     pub fn rank_value(self) -> u8 {
@@ -115,44 +227,220 @@ impl Card {
     pub fn suit_value(self) -> u8 {
         self.suit as u8
     }
+
+    /// Blackjack value: pip value for 2-10, 10 for face cards and 11 for an Ace
+    ///
+    /// Counting an Ace as 1 is decided at the hand level by `blackjack_hand_value`.
+    pub fn blackjack_value(self) -> u8 {
+        match self.rank {
+            Rank::Jack | Rank::Queen | Rank::King => 10,
+            Rank::Ace => 11,
+            rank => rank.value(),
+        }
+    }
+
+    /// Dense index in `0..52`: `(suit_value - 1) * 13 + (rank_value - 2)`
+    ///
+    /// Spades take 0-12, then Hearts, Diamonds and Clubs, each running from
+    /// Two to Ace, so a card's index is its position in `FrenchDeck::new()`.
+    pub fn to_index(self) -> u8 {
+        (self.suit_value() - 1) * 13 + (self.rank_value() - 2)
+    }
+
+    /// Inverse of `to_index`; `None` for indices of 52 and above
+    pub fn from_index(index: u8) -> Option<Card> {
+        let suit = Suit::all().nth(usize::from(index / 13))?;
+        let rank = Rank::all().nth(usize::from(index % 13))?;
+        Some(Card::new(rank, suit))
+    }
+
+    /// Compare two cards using the given ordering strategy
+    ///
+    /// Pass it to `sort_by`, e.g. `cards.sort_by(|a, b| a.cmp_with(b, CardOrdering::SpadesHigh))`.
+    pub fn cmp_with(&self, other: &Card, ordering: CardOrdering) -> std::cmp::Ordering {
+        match ordering {
+            CardOrdering::RankThenSuit => self.cmp(other),
+            CardOrdering::SpadesHigh => spades_high_rank(self).cmp(&spades_high_rank(other)),
+            CardOrdering::SuitThenRank => {
+                self.suit.cmp(&other.suit).then(self.rank.cmp(&other.rank))
+            }
+        }
+    }
+
+    /// The card's glyph from the Unicode Playing Cards block, e.g. `'🂡'` for the Ace of Spades
+    ///
+    /// Each suit has a row starting at U+1F0A0 (Spades), U+1F0B0 (Hearts),
+    /// U+1F0C0 (Diamonds) and U+1F0D0 (Clubs). Within a row the offset is 1
+    /// for the Ace, the pip value for 2-10, 0xB for the Jack, 0xD for the Queen
+    /// and 0xE for the King; 0xC is the tarot Knight, which a French deck lacks.
+    pub fn unicode_char(self) -> char {
+        let suit_base: u32 = match self.suit {
+            Suit::Spades => 0x1F0A0,
+            Suit::Hearts => 0x1F0B0,
+            Suit::Diamonds => 0x1F0C0,
+            Suit::Clubs => 0x1F0D0,
+        };
+        let rank_offset = match self.rank {
+            Rank::Ace => 0x1,
+            Rank::Queen => 0xD,
+            Rank::King => 0xE,
+            rank => u32::from(rank.value()),
+        };
+        char::from_u32(suit_base + rank_offset).expect("playing card code points are valid chars")
+    }
+
+    /// ASCII card code such as `"AS"` or `"10H"`, which parses back with `FromStr`
+    pub fn to_code(self) -> String {
+        format!("{}{}", self.rank.short_name(), self.suit.letter())
+    }
+
+    /// Compact form using the Unicode suit symbol, e.g. `"A♠"` or `"10♥"`
+    pub fn to_short_string(self) -> String {
+        format!("{}{}", self.rank.short_name(), self.suit.symbol())
+    }
 }
 
-impl fmt::Display for Card {
+impl<R: fmt::Display, S: fmt::Display> fmt::Display for Card<R, S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{} of {}", self.rank, self.suit)
     }
 }
 
-impl PartialOrd for Card {
+impl<R: Ord, S: Ord> PartialOrd for Card<R, S> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl Ord for Card {
+impl<R: Ord, S: Ord> Ord for Card<R, S> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         // Compare by rank first, then by suit
         self.rank.cmp(&other.rank).then(self.suit.cmp(&other.suit))
     }
 }
 
-/// A French deck of 52 playing cards
+/// Comparison strategies for `Card::cmp_with`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CardOrdering {
+    /// Rank first, then suit in declaration order; the same as `Card`'s `Ord`
+    #[default]
+    RankThenSuit,
+    /// Rank first, then suit by `spades_high_rank` (clubs < diamonds < hearts < spades)
+    SpadesHigh,
+    /// Suit in declaration order first, then rank
+    SuitThenRank,
+}
+
+/// Convert a rank value (2-14, Ace high) as returned by `Rank::value`
+impl TryFrom<u8> for Rank {
+    type Error = ParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Rank::all()
+            .find(|rank| rank.value() == value)
+            .ok_or_else(|| ParseError::InvalidRank(value.to_string()))
+    }
+}
+
+/// Convert a suit value (1-4) as returned by `Card::suit_value`
+impl TryFrom<u8> for Suit {
+    type Error = ParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Suit::all()
+            .find(|&suit| suit as u8 == value)
+            .ok_or_else(|| ParseError::InvalidSuit(value.to_string()))
+    }
+}
+
+/// Convert a suit letter (`S`, `H`, `D` or `C`, case-insensitive)
+impl TryFrom<char> for Suit {
+    type Error = ParseError;
+
+    fn try_from(letter: char) -> Result<Self, Self::Error> {
+        Suit::all()
+            .find(|suit| suit.letter() == letter.to_ascii_uppercase())
+            .ok_or_else(|| ParseError::InvalidSuit(letter.to_string()))
+    }
+}
+
+/// Parse compact notation such as `"AS"`, `"10h"` or `"tc"`
+///
+/// A rank token (`2`-`10`, `T` for ten, `J`, `Q`, `K`, `A`) is followed by a
+/// suit letter (`S`, `H`, `D`, `C`). Parsing is case-insensitive and ignores
+/// surrounding whitespace.
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let suit_char = s.chars().last().ok_or(ParseError::Empty)?;
+        let rank_token = &s[..s.len() - suit_char.len_utf8()];
+
+        let suit = Suit::try_from(suit_char)?;
+        let rank = match rank_token.to_ascii_uppercase().as_str() {
+            "2" => Rank::Two,
+            "3" => Rank::Three,
+            "4" => Rank::Four,
+            "5" => Rank::Five,
+            "6" => Rank::Six,
+            "7" => Rank::Seven,
+            "8" => Rank::Eight,
+            "9" => Rank::Nine,
+            "10" | "T" => Rank::Ten,
+            "J" => Rank::Jack,
+            "Q" => Rank::Queen,
+            "K" => Rank::King,
+            "A" => Rank::Ace,
+            _ => return Err(ParseError::InvalidRank(rank_token.to_string())),
+        };
+
+        Ok(Card::new(rank, suit))
+    }
+}
+
+/// A deck of playing cards, generic over the rank type `R` and suit type `S`
+///
+/// Dealing, shuffling and the other operations that don't care what the
+/// cards are work for any `Deck<R, S>`. The French-card helpers (suit and
+/// rank queries, compact strings, ...) live on `FrenchDeck`, which is what
+/// the defaults give: `Deck::new()` builds the standard 52-card deck.
+/// Other layouts (Euchre, pinochle, ...) come from `Deck::builder()` or
+/// `Deck::from_cards`, and `DeckBuilder` can also swap in custom rank and
+/// suit types.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct FrenchDeck {
-    cards: Vec<Card>,
+pub struct Deck<R = Rank, S = Suit> {
+    cards: Vec<Card<R, S>>,
 }
 
-impl FrenchDeck {
+/// The standard 52-card French deck from Chapter 1
+pub type FrenchDeck = Deck<Rank, Suit>;
+
+impl Deck {
     /// Create a new deck of 52 cards in standard order
     pub fn new() -> Self {
-        let mut cards = Vec::with_capacity(52);
-        
-        for suit in Suit::all() {
-            for rank in Rank::all() {
-                cards.push(Card::new(rank, suit));
-            }
-        }
-        
+        Self::builder().build()
+    }
+
+    /// Create a four-suit deck holding only the listed ranks, e.g. a 32-card piquet deck
+    ///
+    /// Cards keep the same suit-then-rank order as `new()` regardless of the
+    /// order of `ranks`, and repeated ranks are ignored.
+    pub fn with_ranks(ranks: &[Rank]) -> Self {
+        Self::builder()
+            .ranks(Rank::all().filter(|rank| ranks.contains(rank)))
+            .build()
+    }
+
+    /// Start building a custom deck, defaulting to one copy of every rank and suit
+    pub fn builder() -> DeckBuilder {
+        DeckBuilder::default()
+    }
+}
+
+impl<R, S> Deck<R, S> {
+    /// Create a deck from an explicit list of cards, kept in the given order
+    pub fn from_cards(cards: Vec<Card<R, S>>) -> Self {
         Self { cards }
     }
 
@@ -167,26 +455,366 @@ impl FrenchDeck {
     }
 
     /// Get a card by index
-    pub fn get(&self, index: usize) -> Option<&Card> {
+    pub fn get(&self, index: usize) -> Option<&Card<R, S>> {
         self.cards.get(index)
     }
 
     /// Get a slice of cards
-    pub fn slice(&self, range: std::ops::Range<usize>) -> &[Card] {
+    pub fn slice(&self, range: std::ops::Range<usize>) -> &[Card<R, S>] {
         &self.cards[range]
     }
 
+    /// Get a card by index, counting from the end for negative indices
+    ///
+    /// Like Python's `deck[-1]`, an index of `-1` is the last card.
+    pub fn get_wrapped(&self, index: isize) -> Option<&Card<R, S>> {
+        let index = if index < 0 {
+            self.cards.len().checked_sub(index.unsigned_abs())?
+        } else {
+            index as usize
+        };
+        self.cards.get(index)
+    }
+
+    /// Get every `step`-th card in `start..stop`, like Python's `deck[start:stop:step]`
+    ///
+    /// `stop` is clamped to the deck length. Panics if `step` is zero.
+    pub fn step_slice(&self, start: usize, stop: usize, step: usize) -> Vec<&Card<R, S>> {
+        assert!(step != 0, "slice step cannot be zero");
+        let stop = stop.min(self.cards.len());
+        self.cards
+            .get(start..stop)
+            .unwrap_or_default()
+            .iter()
+            .step_by(step)
+            .collect()
+    }
+
+    /// Split the deck into consecutive groups of `size` cards, in index order
+    ///
+    /// The final chunk holds the remainder and may be smaller. Panics if
+    /// `size` is zero, like `slice::chunks`.
+    pub fn chunks(&self, size: usize) -> Vec<&[Card<R, S>]> {
+        self.cards.chunks(size).collect()
+    }
+
     /// Shuffle the deck in place
     pub fn shuffle(&mut self) {
         let mut rng = thread_rng();
-        self.cards.shuffle(&mut rng);
+        self.shuffle_with(&mut rng);
+    }
+
+    /// Shuffle the deck in place using the given RNG
+    ///
+    /// Passing a seeded RNG (such as `StdRng::seed_from_u64`) makes the
+    /// resulting order reproducible. Every randomized deck operation takes an
+    /// `Rng` the same way, so a whole game can be replayed from one seed by
+    /// threading the same RNG through each call.
+    pub fn shuffle_with<G: Rng>(&mut self, rng: &mut G) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Perform one riffle shuffle following the Gilbert-Shannon-Reeds model
+    ///
+    /// The deck is cut at a Binomial(n, 1/2) point, then cards drop from the
+    /// two packets with probability proportional to each packet's remaining
+    /// size. This models an imperfect human riffle rather than a uniform shuffle.
+    pub fn riffle_shuffle<G: Rng>(&mut self, rng: &mut G) {
+        let cut = (0..self.cards.len()).filter(|_| rng.gen_bool(0.5)).count();
+        let right = self.cards.split_off(cut);
+        let left = std::mem::take(&mut self.cards);
+
+        let mut left = left.into_iter().peekable();
+        let mut right = right.into_iter().peekable();
+        let (mut left_len, mut right_len) = (cut, right.len());
+        self.cards.reserve(left_len + right_len);
+
+        while left_len + right_len > 0 {
+            let from_left = rng.gen_range(0..left_len + right_len) < left_len;
+            let card = if from_left {
+                left_len -= 1;
+                left.next()
+            } else {
+                right_len -= 1;
+                right.next()
+            };
+            self.cards.extend(card);
+        }
+    }
+
+    /// Apply `riffle_shuffle` `times` times in a row with the same RNG
+    ///
+    /// Seven riffles are the classic rule of thumb for mixing a 52-card deck.
+    pub fn shuffle_n<G: Rng>(&mut self, times: usize, rng: &mut G) {
+        for _ in 0..times {
+            self.riffle_shuffle(rng);
+        }
+    }
+
+    /// Perform one perfect (faro) shuffle: split in half and interleave exactly
+    ///
+    /// An out-shuffle keeps the top card on top (and, for an even deck, the
+    /// bottom card on the bottom); an in-shuffle moves the top card to second
+    /// from the top. With an odd number of cards the larger half is the one
+    /// whose first card ends up on top. Eight out-shuffles restore a 52-card deck.
+    pub fn faro_shuffle(&mut self, out_shuffle: bool) {
+        let len = self.cards.len();
+        // Work top-first so the interleave reads from the top of the deck
+        self.cards.reverse();
+        let top_len = if out_shuffle { len.div_ceil(2) } else { len / 2 };
+        let bottom = self.cards.split_off(top_len);
+        let top = std::mem::take(&mut self.cards);
+        let (first, second) = if out_shuffle { (top, bottom) } else { (bottom, top) };
+
+        let mut second = second.into_iter();
+        for card in first {
+            self.cards.push(card);
+            self.cards.extend(second.next());
+        }
+        self.cards.reverse();
+    }
+
+    /// Cut the deck at `index`, swapping the two portions
+    ///
+    /// The card at `index` becomes the first card and everything before it
+    /// moves to the end. The index is taken modulo the deck length, so any
+    /// value is valid; cutting an empty deck does nothing.
+    pub fn cut(&mut self, index: usize) {
+        if !self.cards.is_empty() {
+            let index = index % self.cards.len();
+            self.cards.rotate_left(index);
+        }
+    }
+
+    /// Deal the top card, removing it from the deck
+    ///
+    /// The top of the deck is the end of the internal card order (the card at
+    /// index `len() - 1`), so dealing is O(1). Returns `None` when the deck is empty.
+    pub fn deal(&mut self) -> Option<Card<R, S>> {
+        self.cards.pop()
+    }
+
+    /// Look at the card `deal` would return next, without removing it
+    pub fn peek(&self) -> Option<&Card<R, S>> {
+        self.cards.last()
+    }
+
+    /// Look at up to `n` cards from the top, without removing them
+    ///
+    /// Yields the top `min(n, len())` cards in deal order, next card first,
+    /// so it matches what `deal_n(n)` would return.
+    pub fn peek_n(
+        &self,
+        n: usize,
+    ) -> impl ExactSizeIterator<Item = &Card<R, S>> + DoubleEndedIterator {
+        self.cards[self.cards.len().saturating_sub(n)..].iter().rev()
+    }
+
+    /// Check whether the deck holds at least `n` cards
+    pub fn can_deal(&self, n: usize) -> bool {
+        n <= self.cards.len()
+    }
+
+    /// Deal up to `n` cards from the top, in the order `deal` would return them
+    ///
+    /// Never panics: it always returns exactly `min(n, len())` cards, so asking
+    /// for more than remain simply empties the deck. Use `can_deal` first when
+    /// a short hand should be treated as an error.
+    pub fn deal_n(&mut self, n: usize) -> Vec<Card<R, S>> {
+        let split_at = self.cards.len().saturating_sub(n);
+        let mut dealt = self.cards.split_off(split_at);
+        dealt.reverse();
+        dealt
     }
 
+    /// Deal cards one at a time until one satisfies `predicate`
+    ///
+    /// Returns every card drawn in deal order, including the matching card
+    /// last. If no card matches, the whole deck is dealt.
+    pub fn deal_until(&mut self, predicate: impl Fn(&Card<R, S>) -> bool) -> Vec<Card<R, S>> {
+        let mut dealt = Vec::new();
+        while let Some(card) = self.deal() {
+            let found = predicate(&card);
+            dealt.push(card);
+            if found {
+                break;
+            }
+        }
+        dealt
+    }
+
+    /// Deal `cards_each` cards to each of `players` hands, one card per player per round
+    ///
+    /// Errors without dealing anything if the deck holds too few cards; on
+    /// success the deck keeps the undealt remainder.
+    pub fn deal_hands(
+        &mut self,
+        players: usize,
+        cards_each: usize,
+    ) -> Result<Vec<Vec<Card<R, S>>>, String> {
+        let needed = players
+            .checked_mul(cards_each)
+            .filter(|&needed| needed <= self.len())
+            .ok_or_else(|| {
+                format!(
+                    "Cannot deal {} cards to each of {} players from {} cards",
+                    cards_each,
+                    players,
+                    self.len()
+                )
+            })?;
+
+        let mut hands: Vec<Vec<Card<R, S>>> =
+            (0..players).map(|_| Vec::with_capacity(cards_each)).collect();
+        for (i, card) in self.deal_n(needed).into_iter().enumerate() {
+            hands[i % players].push(card);
+        }
+        Ok(hands)
+    }
+
+    /// Remove `n` cards chosen uniformly at random, without replacement
+    ///
+    /// Runs a partial Fisher-Yates shuffle over just the top `n` slots, so it
+    /// costs O(n) rather than shuffling the whole deck. Cards below the top
+    /// slots may be swapped into them, so the remaining order is disturbed.
+    /// Asking for more cards than the deck holds returns all of them.
+    pub fn sample_hand<G: Rng>(&mut self, n: usize, rng: &mut G) -> Vec<Card<R, S>> {
+        let len = self.cards.len();
+        let n = n.min(len);
+        for slot in (len - n..len).rev() {
+            let pick = rng.gen_range(0..=slot);
+            self.cards.swap(pick, slot);
+        }
+        self.cards.split_off(len - n)
+    }
+
+    /// Remove and return a uniformly random card from anywhere in the deck
+    ///
+    /// The drawn card's slot is filled by the top card (`swap_remove`), so this
+    /// is O(1) but disturbs the order of the remaining cards. Returns `None`
+    /// when the deck is empty.
+    pub fn draw_random<G: Rng>(&mut self, rng: &mut G) -> Option<Card<R, S>> {
+        if self.cards.is_empty() {
+            return None;
+        }
+        let index = rng.gen_range(0..self.cards.len());
+        Some(self.cards.swap_remove(index))
+    }
+
+    /// Put a card on top of the deck, where `deal` will take it next
+    ///
+    /// The deck does not enforce uniqueness, so duplicates are allowed.
+    pub fn add(&mut self, card: Card<R, S>) {
+        self.cards.push(card);
+    }
+
+    /// Put a card at the bottom of the deck
+    ///
+    /// Like `add`, this does not check for duplicates.
+    pub fn add_to_bottom(&mut self, card: Card<R, S>) {
+        self.cards.insert(0, card);
+    }
+
+    /// Get an iterator over the cards
+    pub fn iter(&self) -> std::slice::Iter<'_, Card<R, S>> {
+        self.cards.iter()
+    }
+
+    /// Get a mutable iterator over the cards
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Card<R, S>> {
+        self.cards.iter_mut()
+    }
+
+    /// Convert to vector (consumes the deck)
+    pub fn into_vec(self) -> Vec<Card<R, S>> {
+        self.cards
+    }
+
+}
+
+impl<R: PartialEq, S: PartialEq> Deck<R, S> {
+    /// Check if the deck holds the given card
+    pub fn contains(&self, card: &Card<R, S>) -> bool {
+        self.cards.contains(card)
+    }
+
+    /// Remove the first copy of the given card, returning whether one was found
+    ///
+    /// Only a single copy is removed even if the deck holds duplicates.
+    pub fn remove(&mut self, card: &Card<R, S>) -> bool {
+        match self.cards.iter().position(|c| c == card) {
+            Some(index) => {
+                self.cards.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Deck {
+
     /// Get all cards of a specific suit
     pub fn cards_by_suit(&self, suit: Suit) -> Vec<&Card> {
         self.cards.iter().filter(|card| card.suit == suit).collect()
     }
 
+    /// Group the cards by suit, keeping deck order within each group
+    pub fn group_by_suit(&self) -> HashMap<Suit, Vec<Card>> {
+        let mut groups: HashMap<Suit, Vec<Card>> = HashMap::new();
+        for &card in &self.cards {
+            groups.entry(card.suit).or_default().push(card);
+        }
+        groups
+    }
+
+    /// Count the cards of each rank
+    pub fn count_by_rank(&self) -> HashMap<Rank, usize> {
+        let mut counts = HashMap::new();
+        for card in &self.cards {
+            *counts.entry(card.rank).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Probability that a card drawn from the current deck has the given suit
+    ///
+    /// Like the other probability helpers this reflects the cards still in the
+    /// deck, and an empty deck gives `0.0`.
+    pub fn probability_of_suit(&self, suit: Suit) -> f64 {
+        self.probability_of(|card| card.suit == suit)
+    }
+
+    /// Probability that a card drawn from the current deck has the given rank
+    pub fn probability_of_rank(&self, rank: Rank) -> f64 {
+        self.probability_of(|card| card.rank == rank)
+    }
+
+    /// Probability that at least one of `draws` cards drawn without replacement
+    /// matches `predicate`
+    ///
+    /// Uses the hypergeometric complement: one minus the chance that every
+    /// draw misses.
+    pub fn probability_at_least_one(&self, predicate: impl Fn(&Card) -> bool, draws: usize) -> f64 {
+        let total = self.len();
+        let misses = self.cards.iter().filter(|card| !predicate(card)).count();
+        if draws > misses {
+            return if total == misses { 0.0 } else { 1.0 };
+        }
+        let all_miss: f64 = (0..draws)
+            .map(|i| (misses - i) as f64 / (total - i) as f64)
+            .product();
+        1.0 - all_miss
+    }
+
+    fn probability_of(&self, predicate: impl Fn(&Card) -> bool) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let matches = self.cards.iter().filter(|card| predicate(card)).count();
+        matches as f64 / self.len() as f64
+    }
+
     /// Find the highest card by rank
     pub fn highest_card(&self) -> Option<&Card> {
         self.cards.iter().max_by_key(|card| card.rank_value())
@@ -195,37 +823,78 @@ impl FrenchDeck {
     /// Sort cards by rank (high to low by default, or custom function)
     pub fn sort_by_rank(&mut self, reverse: bool) {
         if reverse {
-            self.cards.sort_by(|a, b| b.rank.cmp(&a.rank));
+            self.cards.sort_by_key(|card| std::cmp::Reverse(card.rank));
         } else {
-            self.cards.sort_by(|a, b| a.rank.cmp(&b.rank));
+            self.cards.sort_by_key(|card| card.rank);
         }
     }
 
-    /// Get an iterator over the cards
-    pub fn iter(&self) -> std::slice::Iter<'_, Card> {
-        self.cards.iter()
+    /// Sort cards ascending by `spades_high_rank`, as in the book's sorted deck
+    ///
+    /// The 2 of Clubs comes first and the Ace of Spades last.
+    pub fn sort_spades_high(&mut self) {
+        self.cards.sort_by_key(spades_high_rank);
     }
 
-    /// Get a mutable iterator over the cards
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Card> {
-        self.cards.iter_mut()
+    /// Check if both decks hold the same multiset of cards, ignoring order
+    ///
+    /// Use `==` instead to also compare the order.
+    pub fn same_cards_as(&self, other: &Deck) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        let mut mine = self.cards.clone();
+        let mut theirs = other.cards.clone();
+        mine.sort();
+        theirs.sort();
+        mine == theirs
     }
 
-    /// Convert to vector (consumes the deck)
-    pub fn into_vec(self) -> Vec<Card> {
+    /// Serialize the deck as space-separated card codes, e.g. `"2S 3S ... AC"`
+    ///
+    /// Cards appear in index order (bottom of the deck first), and
+    /// `from_compact_string` restores exactly the same order.
+    pub fn to_compact_string(&self) -> String {
         self.cards
+            .iter()
+            .map(|card| card.to_code())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Rebuild a deck from `to_compact_string` output
+    ///
+    /// Codes may be separated by any whitespace and use any case. An empty
+    /// string gives an empty deck; the first invalid code is reported as an error.
+    pub fn from_compact_string(s: &str) -> Result<Deck, ParseError> {
+        s.split_whitespace().map(str::parse).collect()
+    }
+
+    /// Serialize the deck as one byte per card, using `Card::to_index`
+    ///
+    /// Bytes follow the deck's index order, so a full deck takes 52 bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.cards.iter().map(|card| card.to_index()).collect()
+    }
+
+    /// Rebuild a deck from `to_bytes` output, rejecting any byte of 52 or above
+    pub fn from_bytes(bytes: &[u8]) -> Result<Deck, ParseError> {
+        bytes
+            .iter()
+            .map(|&byte| Card::from_index(byte).ok_or(ParseError::InvalidIndex(byte)))
+            .collect()
     }
 }
 
-impl Default for FrenchDeck {
+impl Default for Deck {
     fn default() -> Self {
         Self::new()
     }
 }
 
 /// Index access for deck[index]
-impl std::ops::Index<usize> for FrenchDeck {
-    type Output = Card;
+impl<R, S> std::ops::Index<usize> for Deck<R, S> {
+    type Output = Card<R, S>;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.cards[index]
@@ -233,90 +902,706 @@ impl std::ops::Index<usize> for FrenchDeck {
 }
 
 /// Iteration support
-impl IntoIterator for FrenchDeck {
-    type Item = Card;
-    type IntoIter = std::vec::IntoIter<Card>;
+impl<R, S> IntoIterator for Deck<R, S> {
+    type Item = Card<R, S>;
+    type IntoIter = std::vec::IntoIter<Card<R, S>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.cards.into_iter()
     }
 }
 
-impl<'a> IntoIterator for &'a FrenchDeck {
-    type Item = &'a Card;
-    type IntoIter = std::slice::Iter<'a, Card>;
+impl<'a, R, S> IntoIterator for &'a Deck<R, S> {
+    type Item = &'a Card<R, S>;
+    type IntoIter = std::slice::Iter<'a, Card<R, S>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.cards.iter()
     }
 }
 
-impl<'a> IntoIterator for &'a mut FrenchDeck {
-    type Item = &'a mut Card;
-    type IntoIter = std::slice::IterMut<'a, Card>;
+impl<'a, R, S> IntoIterator for &'a mut Deck<R, S> {
+    type Item = &'a mut Card<R, S>;
+    type IntoIter = std::slice::IterMut<'a, Card<R, S>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.cards.iter_mut()
     }
 }
 
-/// Ranking function for spades-high ordering (like in the Python example)
-pub fn spades_high_rank(card: &Card) -> (u8, u8) {
-    // Return (rank_value, suit_priority) where spades = highest priority
-    let suit_priority = match card.suit {
-        Suit::Spades => 4,
-        Suit::Hearts => 3,
-        Suit::Diamonds => 2,
-        Suit::Clubs => 1,
-    };
-    (card.rank_value(), suit_priority)
+/// Collect any card iterator into a deck, keeping iteration order
+impl<R, S> FromIterator<Card<R, S>> for Deck<R, S> {
+    fn from_iter<I: IntoIterator<Item = Card<R, S>>>(iter: I) -> Self {
+        Deck::from_cards(iter.into_iter().collect())
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Append cards on top of the deck, in iteration order
+impl<R, S> Extend<Card<R, S>> for Deck<R, S> {
+    fn extend<I: IntoIterator<Item = Card<R, S>>>(&mut self, iter: I) {
+        self.cards.extend(iter);
+    }
+}
 
-    #[test]
-    fn test_card_creation() {
-        let card = Card::new(Rank::Ace, Suit::Spades);
-        assert_eq!(card.rank, Rank::Ace);
-        assert_eq!(card.suit, Suit::Spades);
-        assert_eq!(card.to_string(), "Ace of Spades");
+/// A casino shoe: several French decks dealt as a single pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Shoe {
+    cards: Deck,
+    num_decks: usize,
+}
+
+impl Shoe {
+    /// Create a shoe of `num_decks` French decks, concatenated in standard order
+    pub fn new(num_decks: usize) -> Self {
+        Self {
+            cards: Deck::builder().copies(num_decks).build(),
+            num_decks,
+        }
     }
 
-    #[test]
-    fn test_card_comparison() {
-        let ace_spades = Card::new(Rank::Ace, Suit::Spades);
-        let king_hearts = Card::new(Rank::King, Suit::Hearts);
-        
-        assert!(ace_spades > king_hearts);
-        assert_eq!(ace_spades.rank_value(), 14);
-        assert_eq!(king_hearts.rank_value(), 13);
+    /// Get the number of decks the shoe was built from
+    pub fn num_decks(&self) -> usize {
+        self.num_decks
     }
 
-    #[test]
-    fn test_deck_creation() {
-        let deck = FrenchDeck::new();
-        assert_eq!(deck.len(), 52);
-        assert!(!deck.is_empty());
-        
-        // First card should be 2 of Spades
-        assert_eq!(deck[0], Card::new(Rank::Two, Suit::Spades));
-        
-        // Last card should be Ace of Clubs
-        assert_eq!(deck[51], Card::new(Rank::Ace, Suit::Clubs));
+    /// Get the number of cards left in the shoe
+    pub fn len(&self) -> usize {
+        self.cards.len()
     }
 
-    #[test]
-    fn test_deck_indexing() {
-        let deck = FrenchDeck::new();
-        let first_card = deck.get(0).unwrap();
-        assert_eq!(*first_card, Card::new(Rank::Two, Suit::Spades));
-        
-        assert!(deck.get(52).is_none()); // Out of bounds
+    /// Check if the shoe is empty
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
     }
 
-    #[test]
+    /// Shuffle all cards in the shoe together using the given RNG
+    pub fn shuffle_with<R: Rng>(&mut self, rng: &mut R) {
+        self.cards.shuffle_with(rng);
+    }
+
+    /// Deal the top card from the shoe
+    pub fn deal(&mut self) -> Option<Card> {
+        self.cards.deal()
+    }
+
+    /// Remove `n` random cards from anywhere in the shoe; see `Deck::sample_hand`
+    pub fn sample_hand<R: Rng>(&mut self, n: usize, rng: &mut R) -> Vec<Card> {
+        self.cards.sample_hand(n, rng)
+    }
+
+    /// Get an iterator over the cards left in the shoe
+    pub fn iter(&self) -> std::slice::Iter<'_, Card> {
+        self.cards.iter()
+    }
+}
+
+/// A card in a deck that may contain jokers
+///
+/// Jokers sort above every standard card; a red joker sorts below a black one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DeckCard {
+    Standard(Card),
+    Joker(Color),
+}
+
+impl DeckCard {
+    /// Check if this card is a joker
+    pub fn is_joker(self) -> bool {
+        matches!(self, DeckCard::Joker(_))
+    }
+}
+
+impl From<Card> for DeckCard {
+    fn from(card: Card) -> Self {
+        DeckCard::Standard(card)
+    }
+}
+
+impl fmt::Display for DeckCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckCard::Standard(card) => write!(f, "{}", card),
+            DeckCard::Joker(color) => write!(f, "{} Joker", color),
+        }
+    }
+}
+
+/// A French deck extended with jokers, for games like canasta
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JokerDeck {
+    cards: Vec<DeckCard>,
+}
+
+impl JokerDeck {
+    /// Create the 52 standard cards followed by `jokers` jokers, alternating red and black
+    pub fn new(jokers: usize) -> Self {
+        let mut cards: Vec<DeckCard> = FrenchDeck::new().into_iter().map(DeckCard::from).collect();
+        cards.extend((0..jokers).map(|i| {
+            DeckCard::Joker(if i % 2 == 0 { Color::Red } else { Color::Black })
+        }));
+        Self { cards }
+    }
+
+    /// Get the number of cards in the deck
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Check if the deck is empty
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Get a card by index
+    pub fn get(&self, index: usize) -> Option<&DeckCard> {
+        self.cards.get(index)
+    }
+
+    /// Shuffle the deck in place using the given RNG
+    pub fn shuffle_with<R: Rng>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Get an iterator over the cards
+    pub fn iter(&self) -> std::slice::Iter<'_, DeckCard> {
+        self.cards.iter()
+    }
+}
+
+/// Index access for deck[index]
+impl std::ops::Index<usize> for JokerDeck {
+    type Output = DeckCard;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.cards[index]
+    }
+}
+
+impl IntoIterator for JokerDeck {
+    type Item = DeckCard;
+    type IntoIter = std::vec::IntoIter<DeckCard>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a JokerDeck {
+    type Item = &'a DeckCard;
+    type IntoIter = std::slice::Iter<'a, DeckCard>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.iter()
+    }
+}
+
+/// Builder for decks with custom ranks, suits and number of copies
+///
+/// Cards are laid out copy by copy, and within each copy suit by suit with
+/// the ranks nested inside, the same order as `FrenchDeck::new()`. The
+/// builder starts out French; passing ranks or suits of another type to
+/// `ranks`/`suits` switches the deck's card type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckBuilder<R = Rank, S = Suit> {
+    ranks: Vec<R>,
+    suits: Vec<S>,
+    copies: usize,
+}
+
+impl<R: Clone, S: Clone> DeckBuilder<R, S> {
+    /// Use only the given ranks, in the given order
+    pub fn ranks<R2>(self, ranks: impl IntoIterator<Item = R2>) -> DeckBuilder<R2, S> {
+        DeckBuilder {
+            ranks: ranks.into_iter().collect(),
+            suits: self.suits,
+            copies: self.copies,
+        }
+    }
+
+    /// Use only the given suits, in the given order
+    pub fn suits<S2>(self, suits: impl IntoIterator<Item = S2>) -> DeckBuilder<R, S2> {
+        DeckBuilder {
+            ranks: self.ranks,
+            suits: suits.into_iter().collect(),
+            copies: self.copies,
+        }
+    }
+
+    /// Include each rank/suit combination this many times
+    pub fn copies(mut self, copies: usize) -> Self {
+        self.copies = copies;
+        self
+    }
+
+    /// Build the deck
+    pub fn build(self) -> Deck<R, S> {
+        let mut cards = Vec::with_capacity(self.ranks.len() * self.suits.len() * self.copies);
+
+        for _ in 0..self.copies {
+            for suit in &self.suits {
+                for rank in &self.ranks {
+                    cards.push(Card::new(rank.clone(), suit.clone()));
+                }
+            }
+        }
+
+        Deck::from_cards(cards)
+    }
+}
+
+impl Default for DeckBuilder {
+    fn default() -> Self {
+        Self {
+            ranks: Rank::all().collect(),
+            suits: Suit::all().collect(),
+            copies: 1,
+        }
+    }
+}
+
+/// Iterate over all 52 standard cards without allocating a deck
+///
+/// Yields the cards in the same order as `FrenchDeck::new()`.
+pub fn all_cards() -> impl Iterator<Item = Card> {
+    Suit::all().flat_map(|suit| Rank::all().map(move |rank| Card::new(rank, suit)))
+}
+
+/// Absolute gap between two ranks, with Ace high: `rank_distance(Two, Five) == 3`
+pub fn rank_distance(a: Rank, b: Rank) -> u8 {
+    a.value().abs_diff(b.value())
+}
+
+/// Ranking function for spades-high ordering (like in the Python example)
+pub fn spades_high_rank(card: &Card) -> (u8, u8) {
+    // Return (rank_value, suit_priority) where spades = highest priority
+    let suit_priority = match card.suit {
+        Suit::Spades => 4,
+        Suit::Hearts => 3,
+        Suit::Diamonds => 2,
+        Suit::Clubs => 1,
+    };
+    (card.rank_value(), suit_priority)
+}
+
+/// Best blackjack total for a hand
+///
+/// Aces start at 11 and are downgraded to 1 one at a time while the hand
+/// would otherwise bust. A hand that busts anyway returns its total above 21
+/// (saturating at `u8::MAX`).
+pub fn blackjack_hand_value(cards: &[Card]) -> u8 {
+    let mut total: u32 = cards.iter().map(|card| u32::from(card.blackjack_value())).sum();
+    let mut soft_aces = cards.iter().filter(|card| card.rank == Rank::Ace).count();
+
+    while total > 21 && soft_aces > 0 {
+        total -= 10;
+        soft_aces -= 1;
+    }
+
+    u8::try_from(total).unwrap_or(u8::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_rank_value_ace_low() {
+        assert_eq!(Rank::Ace.value(), 14);
+        assert_eq!(Rank::Ace.value_ace_low(), 1);
+
+        for rank in Rank::all().filter(|&rank| rank != Rank::Ace) {
+            assert_eq!(rank.value_ace_low(), rank.value());
+        }
+    }
+
+    #[test]
+    fn test_rank_steps() {
+        assert_eq!(Rank::King.succ(), Some(Rank::Ace));
+        assert_eq!(Rank::Ace.succ(), None);
+        assert_eq!(Rank::Three.pred(), Some(Rank::Two));
+        assert_eq!(Rank::Two.pred(), None);
+        let successors: Vec<Rank> = Rank::all().filter_map(Rank::succ).collect();
+        assert_eq!(successors, Rank::all().skip(1).collect::<Vec<_>>());
+
+        assert_eq!(rank_distance(Rank::Two, Rank::Five), 3);
+        assert_eq!(rank_distance(Rank::Five, Rank::Two), 3);
+        assert_eq!(rank_distance(Rank::Ace, Rank::Two), 12);
+        assert_eq!(rank_distance(Rank::Jack, Rank::Jack), 0);
+    }
+
+    #[test]
+    fn test_card_creation() {
+        let card = Card::new(Rank::Ace, Suit::Spades);
+        assert_eq!(card.rank, Rank::Ace);
+        assert_eq!(card.suit, Suit::Spades);
+        assert_eq!(card.to_string(), "Ace of Spades");
+    }
+
+    #[test]
+    fn test_cards_of_rank_and_suit() {
+        let aces: Vec<Card> = Card::all_of_rank(Rank::Ace).collect();
+        assert_eq!(
+            aces,
+            vec![
+                Card::new(Rank::Ace, Suit::Spades),
+                Card::new(Rank::Ace, Suit::Hearts),
+                Card::new(Rank::Ace, Suit::Diamonds),
+                Card::new(Rank::Ace, Suit::Clubs),
+            ]
+        );
+
+        let hearts: Vec<Card> = Card::all_of_suit(Suit::Hearts).collect();
+        assert_eq!(hearts.len(), 13);
+        assert!(hearts.iter().all(|card| card.suit == Suit::Hearts));
+        assert_eq!(hearts[0].rank, Rank::Two);
+        assert_eq!(hearts[12].rank, Rank::Ace);
+    }
+
+    #[test]
+    fn test_card_short_string() {
+        assert_eq!(Card::new(Rank::Ace, Suit::Spades).to_short_string(), "A♠");
+        assert_eq!(Card::new(Rank::Ten, Suit::Hearts).to_short_string(), "10♥");
+        assert_eq!(Card::new(Rank::King, Suit::Diamonds).to_short_string(), "K♦");
+        assert_eq!(Card::new(Rank::Two, Suit::Clubs).to_short_string(), "2♣");
+        assert_eq!(Card::new(Rank::Ten, Suit::Spades).to_short_string(), "10♠");
+        assert_eq!(Card::new(Rank::Ten, Suit::Spades).to_code(), "10S");
+        assert_eq!(Card::new(Rank::Queen, Suit::Diamonds).to_code(), "QD");
+
+        // Display is unchanged
+        assert_eq!(Card::new(Rank::Ten, Suit::Spades).to_string(), "10 of Spades");
+    }
+
+    #[test]
+    fn test_unicode_char() {
+        assert_eq!(Card::new(Rank::Ace, Suit::Spades).unicode_char(), '\u{1F0A1}');
+        assert_eq!(Card::new(Rank::King, Suit::Clubs).unicode_char(), '\u{1F0DE}');
+        assert_eq!(Card::new(Rank::Jack, Suit::Hearts).unicode_char(), '\u{1F0BB}');
+        assert_eq!(Card::new(Rank::Queen, Suit::Hearts).unicode_char(), '\u{1F0BD}');
+        assert_eq!(Card::new(Rank::Ten, Suit::Diamonds).unicode_char(), '\u{1F0CA}');
+
+        let glyphs: std::collections::HashSet<char> = all_cards().map(Card::unicode_char).collect();
+        assert_eq!(glyphs.len(), 52);
+        assert!(!glyphs.contains(&'\u{1F0AC}'));
+    }
+
+    #[test]
+    fn test_suit_colors() {
+        let expected = [Color::Black, Color::Red, Color::Red, Color::Black];
+        for (suit, color) in Suit::all().zip(expected) {
+            assert_eq!(suit.color(), color, "{} has the wrong color", suit);
+        }
+    }
+
+    #[test]
+    fn test_card_comparison() {
+        let ace_spades = Card::new(Rank::Ace, Suit::Spades);
+        let king_hearts = Card::new(Rank::King, Suit::Hearts);
+        
+        assert!(ace_spades > king_hearts);
+        assert_eq!(ace_spades.rank_value(), 14);
+        assert_eq!(king_hearts.rank_value(), 13);
+    }
+
+    #[test]
+    fn test_card_ordering_strategies() {
+        let max_by = |ordering| all_cards().max_by(|a, b| a.cmp_with(b, ordering)).unwrap();
+        let ace_spades = Card::new(Rank::Ace, Suit::Spades);
+
+        assert_eq!(max_by(CardOrdering::SpadesHigh), ace_spades);
+        assert_eq!(max_by(CardOrdering::RankThenSuit), Card::new(Rank::Ace, Suit::Clubs));
+        assert_eq!(max_by(CardOrdering::SuitThenRank), Card::new(Rank::Ace, Suit::Clubs));
+
+        let two_clubs = Card::new(Rank::Two, Suit::Clubs);
+        let king_spades = Card::new(Rank::King, Suit::Spades);
+        assert_eq!(
+            two_clubs.cmp_with(&king_spades, CardOrdering::SuitThenRank),
+            std::cmp::Ordering::Greater
+        );
+        assert_eq!(
+            two_clubs.cmp_with(&king_spades, CardOrdering::RankThenSuit),
+            two_clubs.cmp(&king_spades)
+        );
+        assert_eq!(CardOrdering::default(), CardOrdering::RankThenSuit);
+    }
+
+    #[test]
+    fn test_card_index_round_trip() {
+        let indices: Vec<u8> = all_cards().map(Card::to_index).collect();
+        assert_eq!(indices, (0..52).collect::<Vec<u8>>());
+
+        for card in all_cards() {
+            assert_eq!(Card::from_index(card.to_index()), Some(card));
+        }
+        assert_eq!(Card::new(Rank::Ace, Suit::Clubs).to_index(), 51);
+        assert_eq!(Card::from_index(52), None);
+        assert_eq!(Card::from_index(u8::MAX), None);
+    }
+
+    #[test]
+    fn test_rank_and_suit_conversions() {
+        assert_eq!(Rank::try_from(2), Ok(Rank::Two));
+        assert_eq!(Rank::try_from(14), Ok(Rank::Ace));
+        assert_eq!(Rank::try_from(1), Err(ParseError::InvalidRank("1".to_string())));
+        assert_eq!(Rank::try_from(15), Err(ParseError::InvalidRank("15".to_string())));
+
+        assert_eq!(Suit::try_from(1u8), Ok(Suit::Spades));
+        assert_eq!(Suit::try_from(4u8), Ok(Suit::Clubs));
+        assert_eq!(Suit::try_from(0u8), Err(ParseError::InvalidSuit("0".to_string())));
+        assert_eq!(Suit::try_from(5u8).unwrap_err().to_string(), "Invalid suit: \"5\"");
+
+        assert_eq!(Suit::try_from('S'), Ok(Suit::Spades));
+        assert_eq!(Suit::try_from('H'), Ok(Suit::Hearts));
+        assert_eq!(Suit::try_from('D'), Ok(Suit::Diamonds));
+        assert_eq!(Suit::try_from('c'), Ok(Suit::Clubs));
+        assert_eq!(Suit::try_from('X'), Err(ParseError::InvalidSuit("X".to_string())));
+    }
+
+    #[test]
+    fn test_card_parsing() {
+        assert_eq!("AS".parse(), Ok(Card::new(Rank::Ace, Suit::Spades)));
+        assert_eq!("10h".parse(), Ok(Card::new(Rank::Ten, Suit::Hearts)));
+        assert_eq!("tc".parse(), Ok(Card::new(Rank::Ten, Suit::Clubs)));
+        assert_eq!(" qd ".parse(), Ok(Card::new(Rank::Queen, Suit::Diamonds)));
+        assert_eq!("2C".parse(), Ok(Card::new(Rank::Two, Suit::Clubs)));
+    }
+
+    #[test]
+    fn test_card_parsing_errors() {
+        assert_eq!("".parse::<Card>(), Err(ParseError::Empty));
+        assert_eq!("ZZ".parse::<Card>(), Err(ParseError::InvalidSuit("Z".to_string())));
+        assert_eq!("ZS".parse::<Card>(), Err(ParseError::InvalidRank("Z".to_string())));
+        assert_eq!("S".parse::<Card>(), Err(ParseError::InvalidRank(String::new())));
+        assert_eq!("11H".parse::<Card>(), Err(ParseError::InvalidRank("11".to_string())));
+        assert_eq!("A♠".parse::<Card>(), Err(ParseError::InvalidSuit("♠".to_string())));
+    }
+
+    #[test]
+    fn test_deck_creation() {
+        let deck = FrenchDeck::new();
+        assert_eq!(deck.len(), 52);
+        assert!(!deck.is_empty());
+        
+        // First card should be 2 of Spades
+        assert_eq!(deck[0], Card::new(Rank::Two, Suit::Spades));
+        
+        // Last card should be Ace of Clubs
+        assert_eq!(deck[51], Card::new(Rank::Ace, Suit::Clubs));
+    }
+
+    #[test]
+    fn test_custom_decks() {
+        let euchre = Deck::builder()
+            .ranks([Rank::Nine, Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace])
+            .build();
+        assert_eq!(euchre.len(), 24);
+        assert_eq!(euchre[0], Card::new(Rank::Nine, Suit::Spades));
+
+        let pinochle = Deck::builder()
+            .ranks([Rank::Nine, Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace])
+            .copies(2)
+            .build();
+        assert_eq!(pinochle.len(), 48);
+
+        let red_aces = Deck::builder()
+            .ranks([Rank::Ace])
+            .suits([Suit::Hearts, Suit::Diamonds])
+            .build();
+        assert_eq!(
+            red_aces,
+            Deck::from_cards(vec![
+                Card::new(Rank::Ace, Suit::Hearts),
+                Card::new(Rank::Ace, Suit::Diamonds),
+            ])
+        );
+
+        assert_eq!(Deck::builder().build(), FrenchDeck::new());
+
+        let piquet = FrenchDeck::with_ranks(&[
+            Rank::Seven, Rank::Eight, Rank::Nine, Rank::Ten,
+            Rank::Jack, Rank::Queen, Rank::King, Rank::Ace,
+        ]);
+        assert_eq!(piquet.len(), 32);
+        assert!(piquet.iter().all(|card| card.rank >= Rank::Seven));
+        assert_eq!(piquet[0], Card::new(Rank::Seven, Suit::Spades));
+
+        let reordered = FrenchDeck::with_ranks(&[Rank::Ace, Rank::Nine, Rank::Ace]);
+        assert_eq!(reordered.len(), 8);
+        assert_eq!(reordered[0].rank, Rank::Nine);
+        assert_eq!(FrenchDeck::with_ranks(&Rank::all().collect::<Vec<_>>()), FrenchDeck::new());
+    }
+
+    #[test]
+    fn test_custom_card_types() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Colour {
+            Red,
+            Yellow,
+            Green,
+            Blue,
+        }
+
+        let mut uno: Deck<u8, Colour> = Deck::builder()
+            .ranks(0..=9)
+            .suits([Colour::Red, Colour::Yellow, Colour::Green, Colour::Blue])
+            .copies(2)
+            .build();
+        assert_eq!(uno.len(), 80);
+        assert_eq!(uno.peek(), Some(&Card::new(9, Colour::Blue)));
+
+        uno.shuffle_with(&mut StdRng::seed_from_u64(7));
+        let hand = uno.deal_n(7);
+        assert_eq!(uno.len(), 73);
+        assert!(hand.iter().all(|card| card.rank <= 9));
+        uno.extend(hand);
+        assert_eq!(uno.len(), 80);
+
+        assert_eq!(Card::new(7, "Cups").to_string(), "7 of Cups");
+    }
+
+    #[test]
+    fn test_shoe() {
+        let mut shoe = Shoe::new(6);
+        assert_eq!(shoe.len(), 312);
+        assert_eq!(shoe.num_decks(), 6);
+
+        let ace_spades = Card::new(Rank::Ace, Suit::Spades);
+        assert_eq!(shoe.iter().filter(|&&card| card == ace_spades).count(), 6);
+
+        shoe.shuffle_with(&mut StdRng::seed_from_u64(41));
+        assert!(shoe.deal().is_some());
+        assert_eq!(shoe.len(), 311);
+
+        while shoe.deal().is_some() {}
+        assert!(shoe.is_empty());
+    }
+
+    #[test]
+    fn test_sample_hand_from_shoe() {
+        let mut shoe = Shoe::new(6);
+        let hand = shoe.sample_hand(5, &mut StdRng::seed_from_u64(84));
+        let mut replay = Shoe::new(6);
+        assert_eq!(replay.sample_hand(5, &mut StdRng::seed_from_u64(84)), hand);
+
+        assert_eq!(hand.len(), 5);
+        assert_eq!(shoe.len(), 307);
+        for card in &hand {
+            let drawn = hand.iter().filter(|&c| c == card).count();
+            let left = shoe.iter().filter(|&c| c == card).count();
+            assert_eq!(drawn + left, 6);
+        }
+
+        let mut deck = FrenchDeck::new();
+        assert_eq!(deck.sample_hand(53, &mut StdRng::seed_from_u64(84)).len(), 52);
+        assert!(deck.is_empty());
+    }
+
+    #[test]
+    fn test_joker_deck() {
+        let deck = JokerDeck::new(2);
+        assert_eq!(deck.len(), 54);
+
+        let jokers: Vec<&DeckCard> = deck.iter().filter(|card| card.is_joker()).collect();
+        assert_eq!(jokers.len(), 2);
+
+        let ace_spades = DeckCard::from(Card::new(Rank::Ace, Suit::Spades));
+        for joker in &jokers {
+            assert!(**joker > ace_spades);
+        }
+        assert_eq!(deck.iter().max(), Some(&DeckCard::Joker(Color::Black)));
+
+        assert_eq!(DeckCard::Joker(Color::Red).to_string(), "Red Joker");
+        assert_eq!(DeckCard::Joker(Color::Black).to_string(), "Black Joker");
+        assert_eq!(ace_spades.to_string(), "Ace of Spades");
+    }
+
+    #[test]
+    fn test_deck_indexing() {
+        let deck = FrenchDeck::new();
+        let first_card = deck.get(0).unwrap();
+        assert_eq!(*first_card, Card::new(Rank::Two, Suit::Spades));
+        
+        assert!(deck.get(52).is_none()); // Out of bounds
+    }
+
+    #[test]
+    fn test_python_style_slicing() {
+        let deck = FrenchDeck::new();
+        assert_eq!(deck.get_wrapped(-1), Some(&Card::new(Rank::Ace, Suit::Clubs)));
+        assert_eq!(deck.get_wrapped(-52), Some(&deck[0]));
+        assert_eq!(deck.get_wrapped(0), Some(&deck[0]));
+        assert_eq!(deck.get_wrapped(-53), None);
+        assert_eq!(deck.get_wrapped(52), None);
+
+        // deck[::13] picks the first card of each suit
+        let suit_starts = deck.step_slice(0, 52, 13);
+        assert_eq!(suit_starts, vec![&deck[0], &deck[13], &deck[26], &deck[39]]);
+        assert!(suit_starts.iter().all(|card| card.rank == Rank::Two));
+
+        // deck[12::13] picks the aces
+        let aces = deck.step_slice(12, usize::MAX, 13);
+        assert_eq!(aces.len(), 4);
+        assert!(aces.iter().all(|card| card.rank == Rank::Ace));
+
+        assert!(deck.step_slice(60, 70, 1).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "slice step cannot be zero")]
+    fn test_step_slice_zero_step() {
+        FrenchDeck::new().step_slice(0, 52, 0);
+    }
+
+    #[test]
+    fn test_compact_string_round_trip() {
+        let mut deck = FrenchDeck::new();
+        assert!(deck.to_compact_string().starts_with("2S 3S 4S"));
+        assert!(deck.to_compact_string().ends_with("KC AC"));
+
+        deck.shuffle_with(&mut StdRng::seed_from_u64(80));
+        let saved = deck.to_compact_string();
+        assert_eq!(FrenchDeck::from_compact_string(&saved), Ok(deck));
+
+        assert_eq!(FrenchDeck::from_compact_string("  "), Ok(Deck::from_cards(vec![])));
+        assert_eq!(
+            FrenchDeck::from_compact_string("AS 1X KH"),
+            Err(ParseError::InvalidSuit("X".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_chunks() {
+        let deck = FrenchDeck::new();
+        let chunks = deck.chunks(5);
+
+        assert_eq!(chunks.len(), 11);
+        assert!(chunks[..10].iter().all(|chunk| chunk.len() == 5));
+        assert_eq!(chunks[10], deck.slice(50..52));
+        assert_eq!(chunks[1], deck.slice(5..10));
+        assert_eq!(deck.chunks(52), vec![deck.slice(0..52)]);
+        assert!(FrenchDeck::from_cards(vec![]).chunks(5).is_empty());
+    }
+
+    #[test]
+    fn test_byte_round_trip() {
+        let mut deck = FrenchDeck::new();
+        assert_eq!(deck.to_bytes(), (0..52).collect::<Vec<u8>>());
+
+        deck.shuffle_with(&mut StdRng::seed_from_u64(94));
+        let bytes = deck.to_bytes();
+        assert_eq!(bytes.len(), 52);
+        assert_eq!(FrenchDeck::from_bytes(&bytes), Ok(deck));
+
+        assert_eq!(FrenchDeck::from_bytes(&[0, 200, 1]), Err(ParseError::InvalidIndex(200)));
+        assert_eq!(
+            ParseError::InvalidIndex(52).to_string(),
+            "Invalid card index: 52 (expected 0-51)"
+        );
+    }
+
+    #[test]
     fn test_deck_iteration() {
         let deck = FrenchDeck::new();
         let mut count = 0;
@@ -328,6 +1613,218 @@ mod tests {
         assert_eq!(count, 52);
     }
 
+    #[test]
+    fn test_faro_shuffle() {
+        let original = FrenchDeck::new();
+        let top = original[51];
+        let bottom = original[0];
+
+        let mut deck = original.clone();
+        deck.faro_shuffle(true);
+        assert_eq!((deck[51], deck[0]), (top, bottom));
+        assert_eq!(deck[50], original[25]);
+        for _ in 1..8 {
+            assert_ne!(deck, original);
+            deck.faro_shuffle(true);
+        }
+        assert_eq!(deck, original);
+
+        let mut deck = original.clone();
+        deck.faro_shuffle(false);
+        assert_eq!(deck[50], top);
+        assert_eq!(deck[51], original[25]);
+        assert!(deck.same_cards_as(&original));
+
+        let mut odd = Deck::from_cards(original.slice(0..5).to_vec());
+        odd.faro_shuffle(true);
+        assert_eq!(
+            odd.into_vec(),
+            vec![original[2], original[0], original[3], original[1], original[4]]
+        );
+    }
+
+    #[test]
+    fn test_cut() {
+        let original = FrenchDeck::new();
+        let mut deck = original.clone();
+        deck.cut(26);
+
+        assert_eq!(deck[0], original[26]);
+        assert_eq!(deck[26], original[0]);
+        assert_eq!(deck.len(), 52);
+
+        let mut wrapped = original.clone();
+        wrapped.cut(52 + 26);
+        assert_eq!(wrapped, deck);
+
+        let mut empty = FrenchDeck::from_cards(Vec::new());
+        empty.cut(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_deal() {
+        let mut deck = FrenchDeck::new();
+        assert_eq!(deck.deal(), Some(Card::new(Rank::Ace, Suit::Clubs)));
+        assert_eq!(deck.len(), 51);
+
+        let hand = deck.deal_n(3);
+        assert_eq!(
+            hand,
+            vec![
+                Card::new(Rank::King, Suit::Clubs),
+                Card::new(Rank::Queen, Suit::Clubs),
+                Card::new(Rank::Jack, Suit::Clubs),
+            ]
+        );
+        assert_eq!(deck.len(), 48);
+
+        while deck.deal().is_some() {}
+        assert!(deck.is_empty());
+        assert_eq!(deck.deal(), None);
+        assert!(deck.deal_n(5).is_empty());
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut deck = FrenchDeck::new();
+        let top = deck.peek().copied();
+        assert_eq!(deck.len(), 52);
+        assert_eq!(deck.deal(), top);
+
+        let peeked: Vec<Card> = deck.peek_n(3).copied().collect();
+        assert_eq!(deck.len(), 51);
+        assert_eq!(deck.peek_n(3).next(), deck.peek());
+        assert_eq!(peeked, deck.clone().deal_n(3));
+        assert_eq!(deck.deal_n(3), peeked);
+
+        assert_eq!(deck.peek_n(100).len(), 48);
+        deck.deal_n(48);
+        assert_eq!(deck.peek(), None);
+        assert_eq!(deck.peek_n(5).len(), 0);
+    }
+
+    #[test]
+    fn test_deal_n_clamps_to_remaining_cards() {
+        let mut deck = FrenchDeck::new();
+        assert!(deck.can_deal(52));
+        assert!(!deck.can_deal(53));
+
+        let dealt = deck.deal_n(100);
+        assert_eq!(dealt.len(), 52);
+        assert!(deck.is_empty());
+        assert!(deck.can_deal(0));
+        assert!(!deck.can_deal(1));
+        assert!(deck.deal_n(usize::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_deal_until() {
+        let mut deck = FrenchDeck::new();
+        deck.shuffle_with(&mut StdRng::seed_from_u64(74));
+        let remaining_before = deck.len();
+
+        let drawn = deck.deal_until(|card| card.rank == Rank::Ace);
+        let (last, before) = drawn.split_last().unwrap();
+        assert_eq!(last.rank, Rank::Ace);
+        assert!(before.iter().all(|card| card.rank != Rank::Ace));
+        assert_eq!(deck.len(), remaining_before - drawn.len());
+
+        // Unshuffled (fresh) deck: the top card is the Ace of Clubs
+        assert_eq!(FrenchDeck::new().deal_until(|card| card.rank == Rank::Ace).len(), 1);
+
+        let mut no_match = FrenchDeck::new();
+        assert_eq!(no_match.deal_until(|_| false).len(), 52);
+        assert!(no_match.is_empty());
+    }
+
+    #[test]
+    fn test_deal_hands() {
+        let mut deck = FrenchDeck::new();
+        let hands = deck.deal_hands(4, 5).unwrap();
+
+        assert_eq!(hands.len(), 4);
+        assert!(hands.iter().all(|hand| hand.len() == 5));
+        assert_eq!(deck.len(), 32);
+
+        // Round-robin: the first player gets the 1st, 5th, 9th... cards off the top
+        assert_eq!(hands[0][0], Card::new(Rank::Ace, Suit::Clubs));
+        assert_eq!(hands[1][0], Card::new(Rank::King, Suit::Clubs));
+        assert_eq!(hands[0][1], Card::new(Rank::Ten, Suit::Clubs));
+
+        assert!(deck.deal_hands(7, 5).is_err());
+        assert_eq!(deck.len(), 32);
+    }
+
+    #[test]
+    fn test_draw_random() {
+        use std::collections::HashSet;
+
+        let mut deck = FrenchDeck::new();
+        let mut rng = StdRng::seed_from_u64(66);
+        let drawn: HashSet<Card> = (0..52).map(|_| deck.draw_random(&mut rng).unwrap()).collect();
+
+        assert_eq!(drawn.len(), 52);
+        assert!(FrenchDeck::new().iter().all(|card| drawn.contains(card)));
+        assert!(deck.is_empty());
+        assert_eq!(deck.draw_random(&mut rng), None);
+    }
+
+    #[test]
+    fn test_add_cards_back() {
+        let mut deck = FrenchDeck::new();
+        let hand = deck.deal_n(5);
+        assert_eq!(deck.len(), 47);
+
+        for card in hand {
+            deck.add(card);
+        }
+        assert_eq!(deck.len(), 52);
+
+        let duplicate = Card::new(Rank::Two, Suit::Spades);
+        deck.add_to_bottom(duplicate);
+        assert_eq!(deck.len(), 53);
+        assert_eq!(deck[0], duplicate);
+        assert_eq!(deck[1], duplicate);
+
+        deck.add(duplicate);
+        assert_eq!(deck.deal(), Some(duplicate));
+    }
+
+    #[test]
+    fn test_collect_and_extend() {
+        let spades: FrenchDeck = FrenchDeck::new()
+            .into_iter()
+            .filter(|card| card.suit == Suit::Spades)
+            .collect();
+        assert_eq!(spades.len(), 13);
+        assert!(spades.iter().all(|card| card.suit == Suit::Spades));
+
+        let mut deck = spades;
+        deck.extend(Rank::all().map(|rank| Card::new(rank, Suit::Hearts)));
+        assert_eq!(deck.len(), 26);
+        assert_eq!(deck.deal(), Some(Card::new(Rank::Ace, Suit::Hearts)));
+    }
+
+    #[test]
+    fn test_contains_and_remove() {
+        let ace_spades = Card::new(Rank::Ace, Suit::Spades);
+        let mut deck = FrenchDeck::new();
+        assert!(deck.contains(&ace_spades));
+
+        assert!(deck.remove(&ace_spades));
+        assert_eq!(deck.len(), 51);
+        assert!(!deck.contains(&ace_spades));
+        assert!(!deck.remove(&ace_spades));
+
+        // Only one copy of a duplicate is removed
+        deck.add(ace_spades);
+        deck.add(ace_spades);
+        assert!(deck.remove(&ace_spades));
+        assert!(deck.contains(&ace_spades));
+        assert_eq!(deck.len(), 52);
+    }
+
     #[test]
     fn test_cards_by_suit() {
         let deck = FrenchDeck::new();
@@ -339,6 +1836,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_group_and_count() {
+        let deck = FrenchDeck::new();
+
+        let by_suit = deck.group_by_suit();
+        assert_eq!(by_suit.len(), 4);
+        assert!(by_suit.values().all(|cards| cards.len() == 13));
+        assert_eq!(by_suit[&Suit::Hearts][0], Card::new(Rank::Two, Suit::Hearts));
+
+        let by_rank = deck.count_by_rank();
+        assert_eq!(by_rank.len(), 13);
+        assert!(by_rank.values().all(|&count| count == 4));
+    }
+
+    #[test]
+    fn test_draw_probabilities() {
+        let mut deck = FrenchDeck::new();
+        assert_eq!(deck.probability_of_suit(Suit::Spades), 0.25);
+        assert_eq!(deck.probability_of_rank(Rank::Ace), 1.0 / 13.0);
+
+        let at_least_one_ace = deck.probability_at_least_one(|card| card.rank == Rank::Ace, 5);
+        assert!((at_least_one_ace - 0.341_158_001_662_203_3).abs() < 1e-12);
+        assert_eq!(deck.probability_at_least_one(|card| card.rank == Rank::Ace, 0), 0.0);
+        assert_eq!(deck.probability_at_least_one(|card| card.rank == Rank::Ace, 49), 1.0);
+
+        // Operates on the remaining cards: the Ace of Clubs has been dealt
+        deck.deal();
+        assert_eq!(deck.probability_of_suit(Suit::Clubs), 12.0 / 51.0);
+        let two_draws_club = deck.probability_at_least_one(|card| card.suit == Suit::Clubs, 2);
+        assert!((two_draws_club - 0.418_823_529_411_764_7).abs() < 1e-12);
+
+        let empty = FrenchDeck::from_cards(Vec::new());
+        assert_eq!(empty.probability_of_suit(Suit::Spades), 0.0);
+        assert_eq!(empty.probability_at_least_one(|_| true, 1), 0.0);
+    }
+
     #[test]
     fn test_shuffle() {
         let mut deck1 = FrenchDeck::new();
@@ -351,6 +1884,114 @@ mod tests {
         assert_ne!(deck1.cards, deck2.cards);
     }
 
+    #[test]
+    fn test_seeded_shuffle_is_reproducible() {
+        let mut deck1 = FrenchDeck::new();
+        let mut deck2 = FrenchDeck::new();
+        deck1.shuffle_with(&mut StdRng::seed_from_u64(2024));
+        deck2.shuffle_with(&mut StdRng::seed_from_u64(2024));
+
+        assert_eq!(deck1, deck2);
+        assert_ne!(deck1, FrenchDeck::new());
+    }
+
+    #[test]
+    fn test_seeded_game_deals_a_fixed_hand() {
+        // Deliberately ChaCha8Rng, not StdRng: StdRng's output can change across rand releases
+        let mut deck = FrenchDeck::new();
+        deck.shuffle_with(&mut ChaCha8Rng::seed_from_u64(42));
+
+        let hand: Vec<Card> = (0..5).map(|_| deck.deal().unwrap()).collect();
+        assert_eq!(
+            hand,
+            vec![
+                Card::new(Rank::King, Suit::Spades),
+                Card::new(Rank::Ten, Suit::Diamonds),
+                Card::new(Rank::Nine, Suit::Spades),
+                Card::new(Rank::Nine, Suit::Clubs),
+                Card::new(Rank::King, Suit::Diamonds),
+            ]
+        );
+        assert_eq!(deck.len(), 47);
+    }
+
+    #[test]
+    fn test_blackjack_values() {
+        assert_eq!(Card::new(Rank::Seven, Suit::Hearts).blackjack_value(), 7);
+        assert_eq!(Card::new(Rank::Queen, Suit::Hearts).blackjack_value(), 10);
+        assert_eq!(Card::new(Rank::Ace, Suit::Hearts).blackjack_value(), 11);
+
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        let nine = Card::new(Rank::Nine, Suit::Clubs);
+        let king = Card::new(Rank::King, Suit::Diamonds);
+
+        assert_eq!(blackjack_hand_value(&[ace, ace, nine]), 21);
+        assert_eq!(blackjack_hand_value(&[ace, king]), 21);
+        assert_eq!(blackjack_hand_value(&[ace, king, nine]), 20);
+        assert_eq!(blackjack_hand_value(&[king, king, nine]), 29);
+        assert_eq!(blackjack_hand_value(&[]), 0);
+    }
+
+    #[test]
+    fn test_same_cards_as() {
+        let deck = FrenchDeck::new();
+        let mut shuffled = deck.clone();
+        shuffled.shuffle_with(&mut StdRng::seed_from_u64(35));
+
+        assert_ne!(shuffled, deck);
+        assert!(shuffled.same_cards_as(&deck));
+
+        let mut short = deck.clone();
+        short.deal();
+        assert!(!short.same_cards_as(&deck));
+
+        short.add(Card::new(Rank::Two, Suit::Spades));
+        assert!(!short.same_cards_as(&deck));
+    }
+
+    #[test]
+    fn test_riffle_shuffle_is_a_permutation() {
+        let original = FrenchDeck::new();
+        let mut deck = original.clone();
+        deck.riffle_shuffle(&mut StdRng::seed_from_u64(37));
+
+        assert_eq!(deck.len(), 52);
+        assert!(deck.same_cards_as(&original));
+        assert_ne!(deck, original);
+    }
+
+    #[test]
+    fn test_shuffle_n_riffles() {
+        let original = FrenchDeck::new();
+        let mut deck = original.clone();
+        deck.shuffle_n(7, &mut StdRng::seed_from_u64(98));
+        let mut replay = original.clone();
+        replay.shuffle_n(7, &mut StdRng::seed_from_u64(98));
+
+        assert_eq!(deck, replay);
+        assert_ne!(deck, original);
+        assert!(deck.same_cards_as(&original));
+
+        // Equivalent to seven individual riffles on one RNG
+        let mut rng = StdRng::seed_from_u64(98);
+        let mut by_hand = original.clone();
+        for _ in 0..7 {
+            by_hand.riffle_shuffle(&mut rng);
+        }
+        assert_eq!(by_hand, deck);
+
+        let mut untouched = original.clone();
+        untouched.shuffle_n(0, &mut rng);
+        assert_eq!(untouched, original);
+    }
+
+    #[test]
+    fn test_all_cards_matches_new_deck() {
+        let cards: Vec<Card> = all_cards().collect();
+        assert_eq!(cards.len(), 52);
+        assert_eq!(cards, FrenchDeck::new().into_vec());
+    }
+
     #[test]
     fn test_spades_high_ranking() {
         let ace_spades = Card::new(Rank::Ace, Suit::Spades);
@@ -363,4 +2004,19 @@ mod tests {
         assert_eq!(spades_rank.0, clubs_rank.0); // Same rank value
         assert!(spades_rank.1 > clubs_rank.1);   // Different suit priority
     }
+
+    #[test]
+    fn test_sort_spades_high() {
+        let mut deck = FrenchDeck::new();
+        deck.shuffle_with(&mut StdRng::seed_from_u64(64));
+        deck.sort_spades_high();
+
+        assert_eq!(deck[0], Card::new(Rank::Two, Suit::Clubs));
+        assert_eq!(deck[1], Card::new(Rank::Two, Suit::Diamonds));
+        assert_eq!(deck[51], Card::new(Rank::Ace, Suit::Spades));
+        assert!(deck
+            .cards
+            .windows(2)
+            .all(|pair| spades_high_rank(&pair[0]) < spades_high_rank(&pair[1])));
+    }
 }
@@ -0,0 +1,139 @@
+//! 2x2 matrices for transforming `Vector`s
+//!
+//! `Matrix2` covers the linear part of 2D transforms: rotation, scaling and
+//! their compositions. Matrices are row-major and act on column vectors, so
+//! `a * b` applies `b` first, then `a`.
+
+use super::vector::Vector;
+use core::ops::Mul;
+use num_traits::Float;
+use serde::{Deserialize, Serialize};
+
+/// A 2x2 matrix `[[m00, m01], [m10, m11]]`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Matrix2 {
+    pub m00: f64,
+    pub m01: f64,
+    pub m10: f64,
+    pub m11: f64,
+}
+
+impl Matrix2 {
+    /// Create a matrix from its entries in row-major order
+    pub fn new(m00: f64, m01: f64, m10: f64, m11: f64) -> Self {
+        Matrix2 { m00, m01, m10, m11 }
+    }
+
+    /// The identity matrix
+    pub fn identity() -> Self {
+        Matrix2::new(1.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Counter-clockwise rotation by `angle` radians, matching `Vector::rotated`
+    pub fn rotation(angle: f64) -> Self {
+        let (sin_a, cos_a) = Float::sin_cos(angle);
+        Matrix2::new(cos_a, -sin_a, sin_a, cos_a)
+    }
+
+    /// Per-axis scaling, matching `Vector::scale_non_uniform`
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Matrix2::new(sx, 0.0, 0.0, sy)
+    }
+
+    /// Calculate the determinant
+    pub fn determinant(self) -> f64 {
+        self.m00 * self.m11 - self.m01 * self.m10
+    }
+
+    /// Calculate the inverse, or `None` if the determinant is exactly zero
+    pub fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+        Some(Matrix2::new(
+            self.m11 / det,
+            -self.m01 / det,
+            -self.m10 / det,
+            self.m00 / det,
+        ))
+    }
+}
+
+impl Mul<Vector> for Matrix2 {
+    type Output = Vector;
+
+    fn mul(self, v: Vector) -> Vector {
+        Vector::new(
+            self.m00 * v.x + self.m01 * v.y,
+            self.m10 * v.x + self.m11 * v.y,
+        )
+    }
+}
+
+impl Mul for Matrix2 {
+    type Output = Matrix2;
+
+    fn mul(self, other: Matrix2) -> Matrix2 {
+        Matrix2::new(
+            self.m00 * other.m00 + self.m01 * other.m10,
+            self.m00 * other.m01 + self.m01 * other.m11,
+            self.m10 * other.m00 + self.m11 * other.m10,
+            self.m10 * other.m01 + self.m11 * other.m11,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    const EPSILON: f64 = 1e-10;
+
+    #[test]
+    fn test_identity() {
+        let v = Vector::new(3.0, -4.0);
+        let m = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(Matrix2::identity() * v, v);
+        assert_eq!(Matrix2::identity() * m, m);
+        assert_eq!(m * Matrix2::identity(), m);
+    }
+
+    #[test]
+    fn test_rotation_and_scale() {
+        let v = Vector::new(3.0, 4.0);
+        for angle in [PI / 2.0, 1.0, -2.5] {
+            assert!((Matrix2::rotation(angle) * v).approx_eq(v.rotated(angle), EPSILON));
+        }
+        assert_eq!(Matrix2::scale(2.0, 0.5) * v, v.scale_non_uniform(2.0, 0.5));
+    }
+
+    #[test]
+    fn test_composition() {
+        let a = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+        let b = Matrix2::new(0.0, -1.0, 1.0, 0.0);
+        let c = Matrix2::new(2.0, 0.0, 1.0, 3.0);
+        let v = Vector::new(5.0, -2.0);
+
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!((a * b) * v, a * (b * v));
+        assert_ne!(a * b, b * a);
+    }
+
+    #[test]
+    fn test_determinant_and_inverse() {
+        let m = Matrix2::new(4.0, 7.0, 2.0, 6.0);
+        assert_eq!(m.determinant(), 10.0);
+        assert_eq!(Matrix2::rotation(0.7).determinant().round(), 1.0);
+
+        let v = Vector::new(1.5, -2.0);
+        let inverse = m.inverse().unwrap();
+        assert!((inverse * (m * v)).approx_eq(v, EPSILON));
+
+        let singular = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+        assert_eq!(singular.determinant(), 0.0);
+        assert_eq!(singular.inverse(), None);
+    }
+}
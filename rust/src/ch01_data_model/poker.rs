@@ -0,0 +1,200 @@
+//! Poker hand evaluation
+//!
+//! This module ranks five-card poker hands built from the `Card` type, with
+//! each category carrying the ranks needed to break ties between hands.
+
+use super::french_deck::{Card, Rank};
+use serde::{Deserialize, Serialize};
+
+/// Poker hand categories, ordered from weakest to strongest
+///
+/// Hands compare by category first, then by the tiebreaker ranks each variant
+/// carries (highest first), so two hands can be compared with `<` and `>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum HandRank {
+    HighCard([Rank; 5]),
+    OnePair { pair: Rank, kickers: [Rank; 3] },
+    TwoPair { high_pair: Rank, low_pair: Rank, kicker: Rank },
+    ThreeOfAKind { trips: Rank, kickers: [Rank; 2] },
+    Straight { high: Rank },
+    Flush([Rank; 5]),
+    FullHouse { trips: Rank, pair: Rank },
+    FourOfAKind { quads: Rank, kicker: Rank },
+    StraightFlush { high: Rank },
+    RoyalFlush,
+}
+
+/// Evaluate a five-card poker hand
+///
+/// The wheel (A-2-3-4-5) counts as a Five-high straight with the Ace low.
+/// Panics if `cards` does not hold exactly 5 cards.
+pub fn evaluate_hand(cards: &[Card]) -> HandRank {
+    assert_eq!(cards.len(), 5, "evaluate_hand requires exactly 5 cards");
+
+    let mut ranks: Vec<Rank> = cards.iter().map(|card| card.rank).collect();
+    ranks.sort_by(|a, b| b.cmp(a));
+
+    let is_flush = cards.iter().all(|card| card.suit == cards[0].suit);
+    let straight_high = is_straight(&ranks);
+
+    // Group equal ranks as (count, rank), largest groups first
+    let mut groups: Vec<(usize, Rank)> = Vec::new();
+    for &rank in &ranks {
+        match groups.last_mut() {
+            Some((count, last)) if *last == rank => *count += 1,
+            _ => groups.push((1, rank)),
+        }
+    }
+    groups.sort_by(|a, b| b.cmp(a));
+
+    let kickers: Vec<Rank> = groups.iter().skip(1).map(|&(_, rank)| rank).collect();
+    let all_ranks = [ranks[0], ranks[1], ranks[2], ranks[3], ranks[4]];
+
+    match (straight_high, is_flush, groups[0].0, groups.len()) {
+        (Some(Rank::Ace), true, _, _) => HandRank::RoyalFlush,
+        (Some(high), true, _, _) => HandRank::StraightFlush { high },
+        (_, _, 4, _) => HandRank::FourOfAKind {
+            quads: groups[0].1,
+            kicker: kickers[0],
+        },
+        (_, _, 3, 2) => HandRank::FullHouse {
+            trips: groups[0].1,
+            pair: groups[1].1,
+        },
+        (_, true, _, _) => HandRank::Flush(all_ranks),
+        (Some(high), false, _, _) => HandRank::Straight { high },
+        (_, _, 3, _) => HandRank::ThreeOfAKind {
+            trips: groups[0].1,
+            kickers: [kickers[0], kickers[1]],
+        },
+        (_, _, 2, 3) => HandRank::TwoPair {
+            high_pair: groups[0].1,
+            low_pair: groups[1].1,
+            kicker: groups[2].1,
+        },
+        (_, _, 2, _) => HandRank::OnePair {
+            pair: groups[0].1,
+            kickers: [kickers[0], kickers[1], kickers[2]],
+        },
+        _ => HandRank::HighCard(all_ranks),
+    }
+}
+
+/// High card of the best straight among `ranks`, or `None` if there is none
+///
+/// Ranks are sorted and deduplicated first, so order and pairs don't matter,
+/// and any five consecutive distinct ranks count (useful for seven-card
+/// hands too). The wheel (A-2-3-4-5) is a Five-high straight with the Ace
+/// low, and only counts when no higher straight is present.
+pub fn is_straight(ranks: &[Rank]) -> Option<Rank> {
+    let mut distinct = ranks.to_vec();
+    distinct.sort_by(|a, b| b.cmp(a));
+    distinct.dedup();
+
+    // Distinct ranks sorted high to low are consecutive exactly when the ends differ by 4
+    let highest_run = distinct
+        .windows(5)
+        .find(|run| run[0].value() == run[4].value() + 4)
+        .map(|run| run[0]);
+    let wheel = [Rank::Ace, Rank::Five, Rank::Four, Rank::Three, Rank::Two];
+    let has_wheel = wheel.iter().all(|rank| distinct.contains(rank));
+    highest_run.or(has_wheel.then_some(Rank::Five))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ch01_data_model::french_deck::Suit;
+
+    fn hand(notation: &str) -> Vec<Card> {
+        notation.split_whitespace().map(|code| code.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_flush() {
+        assert_eq!(
+            evaluate_hand(&hand("2H 9H JH 4H KH")),
+            HandRank::Flush([Rank::King, Rank::Jack, Rank::Nine, Rank::Four, Rank::Two])
+        );
+    }
+
+    #[test]
+    fn test_full_house() {
+        assert_eq!(
+            evaluate_hand(&hand("3S 3D 8C 8H 3H")),
+            HandRank::FullHouse { trips: Rank::Three, pair: Rank::Eight }
+        );
+    }
+
+    #[test]
+    fn test_straights() {
+        assert_eq!(
+            evaluate_hand(&hand("6C 7D 8S 9H TC")),
+            HandRank::Straight { high: Rank::Ten }
+        );
+        assert_eq!(
+            evaluate_hand(&hand("AS 2D 3C 4H 5S")),
+            HandRank::Straight { high: Rank::Five }
+        );
+        assert_eq!(
+            evaluate_hand(&hand("AH 2H 3H 4H 5H")),
+            HandRank::StraightFlush { high: Rank::Five }
+        );
+        assert_eq!(evaluate_hand(&hand("TS JS QS KS AS")), HandRank::RoyalFlush);
+
+        // The wheel is the lowest straight
+        assert!(evaluate_hand(&hand("AS 2D 3C 4H 5S")) < evaluate_hand(&hand("2C 3D 4S 5H 6C")));
+    }
+
+    #[test]
+    fn test_kicker_comparison() {
+        let kings_with_ace = evaluate_hand(&hand("KS KD AC 7H 2S"));
+        let kings_with_queen = evaluate_hand(&hand("KH KC QC 7D 2D"));
+
+        assert!(matches!(kings_with_ace, HandRank::OnePair { pair: Rank::King, .. }));
+        assert!(kings_with_ace > kings_with_queen);
+        assert!(evaluate_hand(&hand("2S 2D 3C 3H 4S")) > kings_with_ace);
+        assert_eq!(
+            evaluate_hand(&hand("AS QD 9C 7H 2S")),
+            HandRank::HighCard([Rank::Ace, Rank::Queen, Rank::Nine, Rank::Seven, Rank::Two])
+        );
+    }
+
+    #[test]
+    fn test_other_categories() {
+        assert_eq!(
+            evaluate_hand(&hand("9S 9D 9C 9H 2S")),
+            HandRank::FourOfAKind { quads: Rank::Nine, kicker: Rank::Two }
+        );
+        assert_eq!(
+            evaluate_hand(&hand("QS QD QC 5H 2S")),
+            HandRank::ThreeOfAKind { trips: Rank::Queen, kickers: [Rank::Five, Rank::Two] }
+        );
+        assert_eq!(
+            evaluate_hand(&hand("4S 4D JC JH AS")),
+            HandRank::TwoPair { high_pair: Rank::Jack, low_pair: Rank::Four, kicker: Rank::Ace }
+        );
+    }
+
+    #[test]
+    fn test_is_straight() {
+        use Rank::*;
+
+        assert_eq!(is_straight(&[Ten, Jack, Queen, King, Ace]), Some(Ace));
+        assert_eq!(is_straight(&[Ace, Two, Three, Four, Five]), Some(Five));
+        assert_eq!(is_straight(&[Two, Three, Four, Six, Seven]), None);
+
+        // Order and duplicates don't matter, and longer hands find the best run
+        assert_eq!(is_straight(&[Nine, Seven, Eight, Six, Five]), Some(Nine));
+        assert_eq!(is_straight(&[Five, Four, Four, Three, Two]), None);
+        assert_eq!(is_straight(&[Ace, Two, Three, Four, Five, Six, Six]), Some(Six));
+        assert_eq!(is_straight(&[King, Ace, Two, Three, Four]), None);
+        assert_eq!(is_straight(&[]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "evaluate_hand requires exactly 5 cards")]
+    fn test_wrong_hand_size() {
+        evaluate_hand(&[Card::new(Rank::Ace, Suit::Spades)]);
+    }
+}
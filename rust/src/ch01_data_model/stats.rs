@@ -0,0 +1,59 @@
+//! Exact poker odds for a standard 52-card deck
+//!
+//! Probabilities are computed by counting combinations rather than by
+//! sampling, so they match the textbook values exactly (up to `f64` rounding).
+
+/// Probability that a random five-card hand is a flush
+///
+/// Counts every hand of a single suit, 4 * C(13, 5) = 5,148, so straight and
+/// royal flushes are included (≈ 0.00198). Excluding them gives ≈ 0.00197.
+pub fn flush_probability() -> f64 {
+    let flushes = 4 * n_choose_k(13, 5);
+    flushes as f64 / n_choose_k(52, 5) as f64
+}
+
+/// Probability that a random five-card hand is exactly one pair (≈ 0.4226)
+///
+/// Chooses the pair's rank and two of its suits, then three other distinct
+/// ranks with any suit each: 13 * C(4, 2) * C(12, 3) * 4^3 = 1,098,240.
+pub fn pair_probability() -> f64 {
+    let pairs = 13 * n_choose_k(4, 2) * n_choose_k(12, 3) * 4u64.pow(3);
+    pairs as f64 / n_choose_k(52, 5) as f64
+}
+
+/// Binomial coefficient C(n, k), or 0 when `k > n`
+///
+/// Each intermediate value is itself a binomial coefficient, so the division
+/// is always exact.
+fn n_choose_k(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_n_choose_k() {
+        assert_eq!(n_choose_k(52, 5), 2_598_960);
+        assert_eq!(n_choose_k(13, 5), 1287);
+        assert_eq!(n_choose_k(4, 0), 1);
+        assert_eq!(n_choose_k(4, 4), 1);
+        assert_eq!(n_choose_k(3, 5), 0);
+    }
+
+    #[test]
+    fn test_hand_probabilities() {
+        assert!((flush_probability() - 5148.0 / 2_598_960.0).abs() < EPSILON);
+        assert!((flush_probability() - 0.00198).abs() < 1e-5);
+
+        assert!((pair_probability() - 1_098_240.0 / 2_598_960.0).abs() < EPSILON);
+        assert!((pair_probability() - 0.422569).abs() < 1e-6);
+    }
+}
@@ -1,12 +1,24 @@
-//! 2D Vector implementation in Rust
+//! 2D and 3D Vector implementations in Rust
 //! 
 //! This module demonstrates Rust's operator overloading capabilities and
 //! provides a high-performance 2D vector implementation with full mathematical
 //! operations.
 
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::Sum;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+use num_traits::{Float, Num, Signed};
+#[cfg(feature = "std")]
+use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
-use std::fmt;
-use std::ops::{Add, Mul, Neg, Sub};
+#[cfg(feature = "std")]
+use std::f64::consts::TAU;
 use thiserror::Error;
 
 /// Error types for Vector operations
@@ -19,18 +31,34 @@ pub enum VectorError {
 }
 
 /// A 2D vector with x and y components
+///
+/// The component type defaults to `f64`, so plain `Vector` keeps meaning
+/// `Vector<f64>`. Integer vectors get the arithmetic that needs no square
+/// root (`dot`, `cross`, component operations); methods such as `magnitude`
+/// and `normalized` require a `Float` component type.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
-pub struct Vector {
-    pub x: f64,
-    pub y: f64,
+pub struct Vector<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Vector {
+impl<T> Vector<T> {
     /// Create a new vector
-    pub fn new(x: f64, y: f64) -> Self {
+    pub fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
+    /// Get the components as an `[x, y]` array
+    pub fn components(self) -> [T; 2] {
+        [self.x, self.y]
+    }
+}
+
+// Constructors whose arguments don't pin down the component type live on
+// `Vector<f64>` only, so that `Vector::zero()` keeps inferring its component
+// type (much like `HashMap::new`). Other component types can use
+// `Vector::default()`.
+impl Vector {
     /// Create a zero vector
     pub fn zero() -> Self {
         Self::new(0.0, 0.0)
@@ -46,65 +74,229 @@ impl Vector {
         Self::new(0.0, 1.0)
     }
 
-    /// Calculate the magnitude (length) of the vector
-    pub fn magnitude(self) -> f64 {
-        (self.x * self.x + self.y * self.y).sqrt()
+    // Named directions follow the mathematical y-up convention: positive y
+    // points up and positive angles turn counter-clockwise. Screen coordinates
+    // with y pointing down need `up` and `down` swapped.
+
+    /// Unit vector pointing right, `(1, 0)`; same as `unit_x`
+    pub fn right() -> Self {
+        Self::unit_x()
+    }
+
+    /// Unit vector pointing up, `(0, 1)`; same as `unit_y`
+    pub fn up() -> Self {
+        Self::unit_y()
+    }
+
+    /// Unit vector pointing left, `(-1, 0)`
+    pub fn left() -> Self {
+        Self::new(-1.0, 0.0)
+    }
+
+    /// Unit vector pointing down, `(0, -1)`
+    pub fn down() -> Self {
+        Self::new(0.0, -1.0)
+    }
+
+    /// Create a uniformly distributed random unit vector
+    #[cfg(feature = "std")]
+    pub fn random_unit<R: Rng>(rng: &mut R) -> Self {
+        Self::from_polar(1.0, rng.gen_range(0.0..TAU))
+    }
+
+    /// Create a random unit vector using the thread-local RNG
+    #[cfg(feature = "std")]
+    pub fn random_unit_thread_rng() -> Self {
+        Self::random_unit(&mut thread_rng())
     }
+}
 
+impl<T: Num + Copy> Vector<T> {
     /// Calculate the squared magnitude (avoiding sqrt for performance)
-    pub fn magnitude_squared(self) -> f64 {
+    pub fn magnitude_squared(self) -> T {
         self.x * self.x + self.y * self.y
     }
 
     /// Check if this is a zero vector
     pub fn is_zero(self) -> bool {
-        self.x == 0.0 && self.y == 0.0
+        self.x.is_zero() && self.y.is_zero()
+    }
+
+    /// Calculate dot product with another vector
+    pub fn dot(self, other: Vector<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Calculate cross product (z-component only for 2D vectors)
+    pub fn cross(self, other: Vector<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Calculate squared distance to another vector
+    pub fn distance_squared_to(self, other: Vector<T>) -> T {
+        (other - self).magnitude_squared()
+    }
+}
+
+impl<T: Signed + Copy + PartialOrd> Vector<T> {
+    /// Calculate the Manhattan (L1) distance to another vector: `|dx| + |dy|`
+    pub fn manhattan_distance_to(self, other: Vector<T>) -> T {
+        let delta = other - self;
+        delta.x.abs() + delta.y.abs()
+    }
+
+    /// Calculate the Chebyshev (L∞) distance to another vector: `max(|dx|, |dy|)`
+    pub fn chebyshev_distance_to(self, other: Vector<T>) -> T {
+        let delta = other - self;
+        let (dx, dy) = (delta.x.abs(), delta.y.abs());
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    /// Rotate 90 degrees counter-clockwise, `(-y, x)`; same as `perpendicular`
+    ///
+    /// The quarter-turn rotations only swap and negate components, so unlike
+    /// `rotated` they are exact and also work for integer vectors.
+    pub fn rotate_90(self) -> Vector<T> {
+        Vector::new(-self.y, self.x)
+    }
+
+    /// Rotate 180 degrees, `(-x, -y)`
+    pub fn rotate_180(self) -> Vector<T> {
+        -self
+    }
+
+    /// Rotate 270 degrees counter-clockwise (90 clockwise), `(y, -x)`
+    pub fn rotate_270(self) -> Vector<T> {
+        Vector::new(self.y, -self.x)
+    }
+}
+
+impl<T: Float> Vector<T> {
+    /// Calculate the magnitude (length) of the vector
+    pub fn magnitude(self) -> T {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    /// Check if both components are finite (neither infinite nor NaN)
+    pub fn is_finite(self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Check if either component is NaN
+    pub fn is_nan(self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// Add, returning `None` if finite inputs overflow to a non-finite result
+    ///
+    /// Inputs that are already non-finite pass through like `+` would.
+    pub fn checked_add(self, rhs: Vector<T>) -> Option<Vector<T>> {
+        let sum = self + rhs;
+        (sum.is_finite() || !self.is_finite() || !rhs.is_finite()).then_some(sum)
+    }
+
+    /// Scale, returning `None` if finite inputs overflow to a non-finite result
+    ///
+    /// Inputs that are already non-finite pass through like `*` would.
+    pub fn checked_mul(self, scalar: T) -> Option<Vector<T>> {
+        let product = self * scalar;
+        (product.is_finite() || !self.is_finite() || !scalar.is_finite()).then_some(product)
     }
 
     /// Normalize the vector to unit length
-    pub fn normalized(self) -> Result<Vector, VectorError> {
+    pub fn normalized(self) -> Result<Vector<T>, VectorError> {
         let mag = self.magnitude();
-        if mag == 0.0 {
+        if mag.is_zero() {
             Err(VectorError::ZeroVectorNormalization)
         } else {
             Ok(Vector::new(self.x / mag, self.y / mag))
         }
     }
 
-    /// Calculate dot product with another vector
-    pub fn dot(self, other: Vector) -> f64 {
-        self.x * other.x + self.y * other.y
+    /// Normalize to unit length, or return the zero vector for a zero input
+    pub fn normalized_or_zero(self) -> Vector<T> {
+        self.normalized_or(Vector::new(T::zero(), T::zero()))
     }
 
-    /// Calculate cross product (z-component only for 2D vectors)
-    pub fn cross(self, other: Vector) -> f64 {
-        self.x * other.y - self.y * other.x
+    /// Normalize to unit length, or return `fallback` for a zero input
+    pub fn normalized_or(self, fallback: Vector<T>) -> Vector<T> {
+        self.normalized().unwrap_or(fallback)
+    }
+
+    /// Scale the vector down so its magnitude does not exceed `max`
+    ///
+    /// Vectors already within bounds are returned unchanged without taking a
+    /// square root. `max` is expected to be non-negative.
+    #[inline]
+    pub fn clamp_magnitude(self, max: T) -> Vector<T> {
+        let mag_sq = self.magnitude_squared();
+        if mag_sq <= max * max {
+            self
+        } else {
+            self * (max / mag_sq.sqrt())
+        }
+    }
+
+    /// Get a vector with the same direction and the given magnitude
+    #[inline]
+    pub fn with_magnitude(self, len: T) -> Result<Vector<T>, VectorError> {
+        self.normalized().map(|unit| unit * len)
     }
 
     /// Calculate distance to another vector
-    pub fn distance_to(self, other: Vector) -> f64 {
+    pub fn distance_to(self, other: Vector<T>) -> T {
         (other - self).magnitude()
     }
 
-    /// Calculate squared distance to another vector
-    pub fn distance_squared_to(self, other: Vector) -> f64 {
-        (other - self).magnitude_squared()
+    /// Step from this point towards `target` by at most `max_delta`
+    ///
+    /// Returns `target` exactly once it is within `max_delta`, so repeated
+    /// calls never overshoot or jitter around it. `max_delta` is expected to
+    /// be non-negative.
+    pub fn move_towards(self, target: Vector<T>, max_delta: T) -> Vector<T> {
+        let delta = target - self;
+        let distance = delta.magnitude();
+        if distance <= max_delta {
+            target
+        } else {
+            self + delta * (max_delta / distance)
+        }
     }
 
     /// Calculate angle with another vector in radians
-    pub fn angle_with(self, other: Vector) -> Result<f64, VectorError> {
+    pub fn angle_with(self, other: Vector<T>) -> Result<T, VectorError> {
         let mag_product = self.magnitude() * other.magnitude();
-        if mag_product == 0.0 {
+        if mag_product.is_zero() {
             Err(VectorError::InvalidOperation(
                 "Cannot calculate angle with zero vector".to_string(),
             ))
         } else {
-            Ok((self.dot(other) / mag_product).acos())
+            // Rounding can push the ratio just outside [-1, 1], where acos is NaN
+            let cos_angle = (self.dot(other) / mag_product).clamp(-T::one(), T::one());
+            Ok(cos_angle.acos())
         }
     }
 
+    /// Calculate angle with another vector in degrees
+    pub fn angle_with_degrees(self, other: Vector<T>) -> Result<T, VectorError> {
+        self.angle_with(other).map(T::to_degrees)
+    }
+
+    /// Calculate the signed angle from this vector to another in radians
+    ///
+    /// The result lies in `(-π, π]` and is positive when `other` is
+    /// counter-clockwise from `self`. Magnitudes do not matter, and if either
+    /// vector is zero the result is `0.0` (following `atan2(0, 0)`).
+    pub fn signed_angle_to(self, other: Vector<T>) -> T {
+        (self.cross(other) + T::zero()).atan2(self.dot(other))
+    }
+
     /// Rotate the vector by an angle in radians
-    pub fn rotated(self, angle: f64) -> Vector {
+    pub fn rotated(self, angle: T) -> Vector<T> {
         let cos_a = angle.cos();
         let sin_a = angle.sin();
         Vector::new(
@@ -113,10 +305,68 @@ impl Vector {
         )
     }
 
+    /// Rotate the vector by an angle in radians around a pivot point
+    pub fn rotate_around(self, pivot: Vector<T>, angle: T) -> Vector<T> {
+        (self - pivot).rotated(angle) + pivot
+    }
+
+    /// Rotate the vector by an angle in degrees
+    pub fn rotated_degrees(self, degrees: T) -> Vector<T> {
+        self.rotated(degrees.to_radians())
+    }
+
+    /// Create a vector from a magnitude and an angle in radians
+    pub fn from_polar(magnitude: T, angle_radians: T) -> Vector<T> {
+        Vector::new(
+            magnitude * angle_radians.cos(),
+            magnitude * angle_radians.sin(),
+        )
+    }
+
+    /// Angle from the positive x-axis in radians, `atan2(y, x)`
+    ///
+    /// The result lies in `(-π, π]`, and the zero vector gives `0.0`. This is
+    /// the angle `from_polar` takes.
+    pub fn angle(self) -> T {
+        if self.is_zero() {
+            return T::zero();
+        }
+        // Adding zero turns a `-0.0` y into `+0.0`, so the negative x-axis
+        // maps to `π` rather than `-π`.
+        (self.y + T::zero()).atan2(self.x)
+    }
+
+    /// Compass heading in degrees: clockwise from north (positive y), in `[0, 360)`
+    ///
+    /// This differs from `angle()` in both origin and direction: `up()` is
+    /// 0°, `right()` is 90°, `down()` 180° and `left()` 270°, whereas
+    /// `angle()` measures counter-clockwise from the positive x-axis in
+    /// radians. The zero vector gives `0.0`.
+    pub fn heading_degrees(self) -> T {
+        let full_turn = T::from(360.0).unwrap();
+        // Swapping the atan2 arguments measures from +y and turns clockwise;
+        // adding zero maps a `-0.0` x onto north rather than `-0.0`
+        let heading = (self.x + T::zero()).atan2(self.y).to_degrees();
+        let heading = if heading < T::zero() { heading + full_turn } else { heading };
+        // A tiny negative angle can round up to exactly 360 after wrapping
+        if heading >= full_turn {
+            T::zero()
+        } else {
+            heading
+        }
+    }
+
+    /// Convert to polar coordinates as `(magnitude, angle)`
+    ///
+    /// The angle is `angle()`, in `(-π, π]`. A zero vector yields `(0.0, 0.0)`.
+    pub fn to_polar(self) -> (T, T) {
+        (self.magnitude(), self.angle())
+    }
+
     /// Project this vector onto another vector
-    pub fn project_onto(self, other: Vector) -> Result<Vector, VectorError> {
+    pub fn project_onto(self, other: Vector<T>) -> Result<Vector<T>, VectorError> {
         let other_mag_sq = other.magnitude_squared();
-        if other_mag_sq == 0.0 {
+        if other_mag_sq.is_zero() {
             Err(VectorError::InvalidOperation(
                 "Cannot project onto zero vector".to_string(),
             ))
@@ -126,23 +376,146 @@ impl Vector {
         }
     }
 
+    /// Split into `(projection, rejection)`: the parts parallel and perpendicular to `onto`
+    ///
+    /// The two parts sum back to `self`. Errors on a zero `onto`, like `project_onto`.
+    pub fn decompose(self, onto: Vector<T>) -> Result<(Vector<T>, Vector<T>), VectorError> {
+        let projection = self.project_onto(onto)?;
+        Ok((projection, self - projection))
+    }
+
     /// Get the perpendicular vector (rotated 90 degrees counter-clockwise)
-    pub fn perpendicular(self) -> Vector {
+    pub fn perpendicular(self) -> Vector<T> {
         Vector::new(-self.y, self.x)
     }
 
-    /// Linear interpolation between this vector and another
-    pub fn lerp(self, other: Vector, t: f64) -> Vector {
+    /// Reflect this vector off a surface with the given normal
+    ///
+    /// The normal is normalized internally, so any non-zero length works.
+    pub fn reflect(self, normal: Vector<T>) -> Result<Vector<T>, VectorError> {
+        match normal.normalized() {
+            Ok(unit_normal) => Ok(self.reflect_unchecked(unit_normal)),
+            Err(_) => Err(VectorError::InvalidOperation(
+                "Cannot reflect off zero normal".to_string(),
+            )),
+        }
+    }
+
+    /// Reflect this vector off a surface with a unit-length normal
+    ///
+    /// Computes `self - 2 * self.dot(n) * n`. The caller must ensure `normal`
+    /// has unit length; otherwise the result is scaled incorrectly.
+    pub fn reflect_unchecked(self, normal: Vector<T>) -> Vector<T> {
+        let two = T::one() + T::one();
+        self - normal * (two * self.dot(normal))
+    }
+
+    /// Mirror this vector across the line through the origin spanned by `line_dir`
+    ///
+    /// Computes `2 * projection - self`. This is the mirror image of `reflect`:
+    /// reflecting across a line flips the component perpendicular to it, while
+    /// reflecting off a surface flips the component along the normal.
+    pub fn reflect_across_line(self, line_dir: Vector<T>) -> Result<Vector<T>, VectorError> {
+        match self.project_onto(line_dir) {
+            Ok(projection) => Ok(projection * (T::one() + T::one()) - self),
+            Err(_) => Err(VectorError::InvalidOperation(
+                "Cannot reflect across a zero direction".to_string(),
+            )),
+        }
+    }
+
+    /// Linear interpolation between this vector and another, with `t` clamped to `[0, 1]`
+    ///
+    /// **Behavior change:** `lerp` used to extrapolate for `t` outside `[0, 1]`;
+    /// it now always returns a point on the segment from `self` to `other`.
+    /// Use `lerp_unclamped` for the old extrapolating behavior.
+    pub fn lerp(self, other: Vector<T>, t: T) -> Vector<T> {
+        self.lerp_unclamped(other, t.max(T::zero()).min(T::one()))
+    }
+
+    /// Linear interpolation that extrapolates for `t` outside `[0, 1]`
+    ///
+    /// `t = 1.5` lands halfway past `other`, and negative `t` lands behind `self`.
+    pub fn lerp_unclamped(self, other: Vector<T>, t: T) -> Vector<T> {
         self + (other - self) * t
     }
 
+    /// Get the point halfway between this vector and another
+    pub fn midpoint(self, other: Vector<T>) -> Vector<T> {
+        (self + other) / (T::one() + T::one())
+    }
+
+    /// Compute the weighted centroid of `(point, weight)` pairs
+    ///
+    /// Useful for center-of-mass calculations. Errors when the weights sum to zero.
+    pub fn weighted_average(points: &[(Vector<T>, T)]) -> Result<Vector<T>, VectorError> {
+        let (weighted_sum, total_weight) = points.iter().fold(
+            (Vector::new(T::zero(), T::zero()), T::zero()),
+            |(sum, total), &(point, weight)| (sum + point * weight, total + weight),
+        );
+        if total_weight.is_zero() {
+            Err(VectorError::InvalidOperation(
+                "Total weight must be non-zero".to_string(),
+            ))
+        } else {
+            Ok(weighted_sum / total_weight)
+        }
+    }
+
+    /// Spherical linear interpolation along the arc between this vector and another
+    ///
+    /// Unlike `lerp`, the direction sweeps at a constant angular rate. Like
+    /// `lerp_unclamped`, values of `t` outside `[0, 1]` extrapolate rather than
+    /// clamp. Nearly parallel (or antiparallel) inputs fall back to
+    /// `lerp_unclamped` to avoid dividing by a vanishing `sin`. Zero vectors
+    /// are rejected with the same error as `angle_with`.
+    pub fn slerp(self, other: Vector<T>, t: T) -> Result<Vector<T>, VectorError> {
+        let omega = self.angle_with(other)?;
+        let sin_omega = omega.sin();
+        if sin_omega.abs() < T::epsilon().sqrt() {
+            return Ok(self.lerp_unclamped(other, t));
+        }
+        let from_weight = ((T::one() - t) * omega).sin() / sin_omega;
+        let to_weight = (t * omega).sin() / sin_omega;
+        Ok(self * from_weight + other * to_weight)
+    }
+
+    /// Interpolate magnitude linearly and direction along the shorter arc
+    ///
+    /// Unlike `slerp`, the endpoints' lengths blend linearly, so the midpoint
+    /// between lengths 1 and 2 has length 1.5. Like `lerp`, `t` is clamped to
+    /// `[0, 1]`. Half-turns (exactly opposite directions) sweep
+    /// counter-clockwise. Zero vectors are rejected with the same error as
+    /// `angle_with`.
+    pub fn lerp_polar(self, other: Vector<T>, t: T) -> Result<Vector<T>, VectorError> {
+        self.angle_with(other)?;
+        let t = t.max(T::zero()).min(T::one());
+        let (from_mag, from_angle) = self.to_polar();
+        let magnitude = from_mag + (other.magnitude() - from_mag) * t;
+        Ok(Vector::from_polar(magnitude, from_angle + self.signed_angle_to(other) * t))
+    }
+
+    /// Check if the vectors are parallel (or antiparallel): `|cross| < epsilon`
+    ///
+    /// A zero vector is considered parallel to every vector.
+    pub fn is_parallel_to(self, other: Vector<T>, epsilon: T) -> bool {
+        self.cross(other).abs() < epsilon
+    }
+
+    /// Check if the vectors are perpendicular: `|dot| < epsilon`
+    ///
+    /// A zero vector is considered perpendicular to every vector.
+    pub fn is_perpendicular_to(self, other: Vector<T>, epsilon: T) -> bool {
+        self.dot(other).abs() < epsilon
+    }
+
     /// Check if vectors are approximately equal (useful for floating point comparison)
-    pub fn approx_eq(self, other: Vector, epsilon: f64) -> bool {
+    pub fn approx_eq(self, other: Vector<T>, epsilon: T) -> bool {
         (self.x - other.x).abs() < epsilon && (self.y - other.y).abs() < epsilon
     }
 }
 
-impl fmt::Display for Vector {
+impl<T: fmt::Display> fmt::Display for Vector<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Vector({}, {})", self.x, self.y)
     }
@@ -150,195 +523,1266 @@ impl fmt::Display for Vector {
 
 // Operator overloading implementations
 
-impl Add for Vector {
-    type Output = Vector;
+impl<T: Add<Output = T>> Add for Vector<T> {
+    type Output = Vector<T>;
 
-    fn add(self, rhs: Vector) -> Vector {
+    fn add(self, rhs: Vector<T>) -> Vector<T> {
         Vector::new(self.x + rhs.x, self.y + rhs.y)
     }
 }
 
-impl Sub for Vector {
-    type Output = Vector;
+impl<T: Sub<Output = T>> Sub for Vector<T> {
+    type Output = Vector<T>;
 
-    fn sub(self, rhs: Vector) -> Vector {
+    fn sub(self, rhs: Vector<T>) -> Vector<T> {
         Vector::new(self.x - rhs.x, self.y - rhs.y)
     }
 }
 
-impl Mul<f64> for Vector {
-    type Output = Vector;
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn mul(self, scalar: f64) -> Vector {
+    fn mul(self, scalar: T) -> Vector<T> {
         Vector::new(self.x * scalar, self.y * scalar)
     }
 }
 
-impl Mul<Vector> for f64 {
-    type Output = Vector;
+/// Component-wise (Hadamard) product, the same as `component_mul`
+///
+/// `v1 * v2` is deliberately *not* the dot product; use `dot` for that.
+impl<T: Mul<Output = T>> Mul for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+/// Component-wise division by a scalar
+///
+/// For floating point components a zero divisor does not panic: it follows
+/// IEEE 754, producing `inf` (or `NaN` for a `0.0` component) just like
+/// dividing the components individually.
+impl<T: Div<Output = T> + Copy> Div<T> for Vector<T> {
+    type Output = Vector<T>;
 
-    fn mul(self, vector: Vector) -> Vector {
-        Vector::new(vector.x * self, vector.y * self)
+    fn div(self, scalar: T) -> Vector<T> {
+        Vector::new(self.x / scalar, self.y / scalar)
     }
 }
 
-impl Neg for Vector {
-    type Output = Vector;
+// Scalar-on-the-left multiplication, `2.0 * v`, for each float type
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {$(
+        impl Mul<Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn mul(self, vector: Vector<$t>) -> Vector<$t> {
+                Vector::new(vector.x * self, vector.y * self)
+            }
+        }
+    )*};
+}
+
+impl_scalar_mul!(f32, f64);
+
+impl<T: Neg<Output = T>> Neg for Vector<T> {
+    type Output = Vector<T>;
 
-    fn neg(self) -> Vector {
+    fn neg(self) -> Vector<T> {
         Vector::new(-self.x, -self.y)
     }
 }
 
-impl Default for Vector {
-    fn default() -> Self {
-        Self::zero()
+impl<T: AddAssign> AddAssign for Vector<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
     }
 }
 
-// Additional convenience methods
-impl Vector {
-    /// Component-wise multiplication (Hadamard product)
-    pub fn component_mul(self, other: Vector) -> Vector {
-        Vector::new(self.x * other.x, self.y * other.y)
+impl<T: SubAssign> SubAssign for Vector<T> {
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
     }
+}
 
-    /// Component-wise division
-    pub fn component_div(self, other: Vector) -> Result<Vector, VectorError> {
-        if other.x == 0.0 || other.y == 0.0 {
-            Err(VectorError::InvalidOperation(
-                "Division by zero component".to_string(),
-            ))
-        } else {
-            Ok(Vector::new(self.x / other.x, self.y / other.y))
-        }
+impl<T: MulAssign + Copy> MulAssign<T> for Vector<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.x *= scalar;
+        self.y *= scalar;
     }
+}
 
-    /// Get the minimum components
-    pub fn min_components(self, other: Vector) -> Vector {
-        Vector::new(self.x.min(other.x), self.y.min(other.y))
+/// In-place counterpart of `Div<T>`, with the same IEEE 754 zero-divisor behavior
+impl<T: DivAssign + Copy> DivAssign<T> for Vector<T> {
+    fn div_assign(&mut self, scalar: T) {
+        self.x /= scalar;
+        self.y /= scalar;
     }
+}
 
-    /// Get the maximum components
-    pub fn max_components(self, other: Vector) -> Vector {
-        Vector::new(self.x.max(other.x), self.y.max(other.y))
+impl<T: Num + Copy> Default for Vector<T> {
+    fn default() -> Self {
+        Self::new(T::zero(), T::zero())
     }
+}
 
-    /// Clamp vector components between min and max values
-    pub fn clamp(self, min: f64, max: f64) -> Vector {
-        Vector::new(self.x.clamp(min, max), self.y.clamp(min, max))
+/// Summation support, e.g. `points.iter().sum::<Vector>()`
+///
+/// A centroid is the sum divided by the count; `centroid` does exactly that.
+impl<T: Num + Copy> Sum for Vector<T> {
+    fn sum<I: Iterator<Item = Vector<T>>>(iter: I) -> Self {
+        iter.fold(Vector::default(), |acc, v| acc + v)
     }
+}
 
-    /// Get vector as tuple
-    pub fn as_tuple(self) -> (f64, f64) {
-        (self.x, self.y)
+impl<'a, T: Num + Copy + 'a> Sum<&'a Vector<T>> for Vector<T> {
+    fn sum<I: Iterator<Item = &'a Vector<T>>>(iter: I) -> Self {
+        iter.copied().sum()
     }
+}
 
-    /// Create vector from tuple
-    pub fn from_tuple(tuple: (f64, f64)) -> Vector {
-        Vector::new(tuple.0, tuple.1)
+/// Index access for v[0] (x) and v[1] (y)
+impl<T> Index<usize> for Vector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Vector index out of range: {} (expected 0 or 1)", index),
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl<T> IndexMut<usize> for Vector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Vector index out of range: {} (expected 0 or 1)", index),
+        }
+    }
+}
 
-    const EPSILON: f64 = 1e-10;
+/// Conversion from a flat slice, which must hold exactly two components
+impl<T: Copy> TryFrom<&[T]> for Vector<T> {
+    type Error = VectorError;
 
-    #[test]
-    fn test_vector_creation() {
-        let v = Vector::new(3.0, 4.0);
-        assert_eq!(v.x, 3.0);
-        assert_eq!(v.y, 4.0);
-        assert_eq!(v.to_string(), "Vector(3, 4)");
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        match *slice {
+            [x, y] => Ok(Vector::new(x, y)),
+            _ => Err(VectorError::InvalidOperation(format!(
+                "Expected 2 components, got {}",
+                slice.len()
+            ))),
+        }
     }
+}
 
-    #[test]
-    fn test_vector_magnitude() {
-        let v = Vector::new(3.0, 4.0);
-        assert_eq!(v.magnitude(), 5.0);
-        assert_eq!(v.magnitude_squared(), 25.0);
-        
-        let zero = Vector::zero();
-        assert_eq!(zero.magnitude(), 0.0);
-        assert!(zero.is_zero());
+impl<T> From<[T; 2]> for Vector<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Vector::new(x, y)
     }
+}
 
-    #[test]
-    fn test_vector_arithmetic() {
-        let v1 = Vector::new(1.0, 2.0);
-        let v2 = Vector::new(3.0, 4.0);
-        
-        assert_eq!(v1 + v2, Vector::new(4.0, 6.0));
-        assert_eq!(v2 - v1, Vector::new(2.0, 2.0));
-        assert_eq!(v1 * 2.0, Vector::new(2.0, 4.0));
-        assert_eq!(2.0 * v1, Vector::new(2.0, 4.0));
-        assert_eq!(-v1, Vector::new(-1.0, -2.0));
+impl<T> From<Vector<T>> for [T; 2] {
+    fn from(v: Vector<T>) -> Self {
+        v.components()
     }
+}
 
-    #[test]
-    fn test_vector_normalization() {
-        let v = Vector::new(3.0, 4.0);
-        let normalized = v.normalized().unwrap();
-        assert!(normalized.magnitude().abs() - 1.0 < EPSILON);
-        
-        let zero = Vector::zero();
-        assert!(zero.normalized().is_err());
+/// Tuple conversions, the `From`/`Into` counterparts of `from_tuple` and `as_tuple`
+impl<T> From<(T, T)> for Vector<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vector::new(x, y)
     }
+}
 
-    #[test]
-    fn test_dot_and_cross_product() {
-        let v1 = Vector::new(1.0, 2.0);
-        let v2 = Vector::new(3.0, 4.0);
-        
-        assert_eq!(v1.dot(v2), 11.0); // 1*3 + 2*4 = 11
-        assert_eq!(v1.cross(v2), -2.0); // 1*4 - 2*3 = -2
+impl<T> From<Vector<T>> for (T, T) {
+    fn from(v: Vector<T>) -> Self {
+        (v.x, v.y)
     }
+}
 
-    #[test]
-    fn test_distance() {
-        let v1 = Vector::new(0.0, 0.0);
-        let v2 = Vector::new(3.0, 4.0);
-        
-        assert_eq!(v1.distance_to(v2), 5.0);
-        assert_eq!(v1.distance_squared_to(v2), 25.0);
+/// Iterate over the components in `[x, y]` order, e.g. `for c in v { .. }`
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components().into_iter()
     }
+}
 
-    #[test]
-    fn test_rotation() {
-        let v = Vector::new(1.0, 0.0);
-        let rotated = v.rotated(std::f64::consts::PI / 2.0); // 90 degrees
-        
-        assert!(rotated.approx_eq(Vector::new(0.0, 1.0), EPSILON));
+/// Convert to `glam::Vec2`
+///
+/// This direction is lossy: `glam::Vec2` stores `f32` components, so each
+/// `f64` component is rounded to the nearest `f32`.
+#[cfg(feature = "glam")]
+impl From<Vector> for glam::Vec2 {
+    fn from(v: Vector) -> Self {
+        glam::Vec2::new(v.x as f32, v.y as f32)
     }
+}
 
-    #[test]
-    fn test_projection() {
-        let v1 = Vector::new(2.0, 3.0);
-        let v2 = Vector::new(1.0, 0.0); // Unit vector in x direction
-        
-        let projected = v1.project_onto(v2).unwrap();
-        assert_eq!(projected, Vector::new(2.0, 0.0));
+/// Convert from `glam::Vec2`, widening each `f32` component to `f64` losslessly
+#[cfg(feature = "glam")]
+impl From<glam::Vec2> for Vector {
+    fn from(v: glam::Vec2) -> Self {
+        Vector::new(f64::from(v.x), f64::from(v.y))
     }
+}
 
-    #[test]
-    fn test_perpendicular() {
-        let v = Vector::new(1.0, 2.0);
-        let perp = v.perpendicular();
-        
-        assert_eq!(perp, Vector::new(-2.0, 1.0));
-        assert_eq!(v.dot(perp), 0.0); // Should be orthogonal
+/// Convert to `nalgebra::Vector2<f64>`
+///
+/// Both types store `f64` components, so unlike the `glam` conversion this is
+/// lossless in both directions.
+#[cfg(feature = "nalgebra")]
+impl From<Vector> for nalgebra::Vector2<f64> {
+    fn from(v: Vector) -> Self {
+        nalgebra::Vector2::new(v.x, v.y)
     }
+}
 
-    #[test]
-    fn test_lerp() {
-        let v1 = Vector::new(0.0, 0.0);
-        let v2 = Vector::new(10.0, 20.0);
-        
-        assert_eq!(v1.lerp(v2, 0.0), v1);
+/// Convert from `nalgebra::Vector2<f64>` losslessly
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector2<f64>> for Vector {
+    fn from(v: nalgebra::Vector2<f64>) -> Self {
+        Vector::new(v.x, v.y)
+    }
+}
+
+// Additional convenience methods
+impl<T: Num + Copy> Vector<T> {
+    /// Component-wise multiplication (Hadamard product)
+    pub fn component_mul(self, other: Vector<T>) -> Vector<T> {
+        Vector::new(self.x * other.x, self.y * other.y)
+    }
+
+    /// Scale each axis by its own factor: `(x * sx, y * sy)`
+    ///
+    /// Equivalent to `component_mul(Vector::new(sx, sy))`, but reads better in
+    /// transform code.
+    pub fn scale_non_uniform(self, sx: T, sy: T) -> Vector<T> {
+        Vector::new(self.x * sx, self.y * sy)
+    }
+
+    /// Component-wise division
+    pub fn component_div(self, other: Vector<T>) -> Result<Vector<T>, VectorError> {
+        if other.x.is_zero() || other.y.is_zero() {
+            Err(VectorError::InvalidOperation(
+                "Division by zero component".to_string(),
+            ))
+        } else {
+            Ok(Vector::new(self.x / other.x, self.y / other.y))
+        }
+    }
+
+    /// Get vector as tuple
+    pub fn as_tuple(self) -> (T, T) {
+        (self.x, self.y)
+    }
+
+    /// Create vector from tuple
+    pub fn from_tuple(tuple: (T, T)) -> Vector<T> {
+        Vector::new(tuple.0, tuple.1)
+    }
+
+    /// Apply the linear map whose matrix has `basis` as its columns: `x * basis[0] + y * basis[1]`
+    ///
+    /// `basis` holds the images of `unit_x` and `unit_y`. This matches
+    /// multiplying by a `Matrix2` with those columns, without building one.
+    pub fn transform(self, basis: [Vector<T>; 2]) -> Vector<T> {
+        basis[0] * self.x + basis[1] * self.y
+    }
+
+    /// Swizzle: swap the components, `(y, x)`
+    pub fn yx(self) -> Vector<T> {
+        Vector::new(self.y, self.x)
+    }
+
+    /// Swizzle: broadcast `x` to both components, `(x, x)`
+    pub fn xx(self) -> Vector<T> {
+        Vector::new(self.x, self.x)
+    }
+
+    /// Swizzle: broadcast `y` to both components, `(y, y)`
+    pub fn yy(self) -> Vector<T> {
+        Vector::new(self.y, self.y)
+    }
+}
+
+impl<T: PartialOrd + Copy> Vector<T> {
+    /// Get the minimum components
+    ///
+    /// As with `f64::min`, a NaN component loses to a non-NaN one.
+    pub fn min_components(self, other: Vector<T>) -> Vector<T> {
+        Vector::new(partial_min(self.x, other.x), partial_min(self.y, other.y))
+    }
+
+    /// Get the maximum components
+    ///
+    /// As with `f64::max`, a NaN component loses to a non-NaN one.
+    pub fn max_components(self, other: Vector<T>) -> Vector<T> {
+        Vector::new(partial_max(self.x, other.x), partial_max(self.y, other.y))
+    }
+
+    /// Clamp vector components between min and max values
+    pub fn clamp(self, min: T, max: T) -> Vector<T> {
+        Vector::new(num_traits::clamp(self.x, min, max), num_traits::clamp(self.y, min, max))
+    }
+}
+
+/// The smaller of `a` and `b`, or the other one if either is unordered (NaN)
+fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a || a.partial_cmp(&a).is_none() { b } else { a }
+}
+
+/// The larger of `a` and `b`, or the other one if either is unordered (NaN)
+fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a || a.partial_cmp(&a).is_none() { b } else { a }
+}
+
+impl<T: Float> Vector<T> {
+    /// Get the absolute value of each component
+    pub fn abs(self) -> Vector<T> {
+        Vector::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Round each component down to the nearest integer
+    pub fn floor(self) -> Vector<T> {
+        Vector::new(self.x.floor(), self.y.floor())
+    }
+
+    /// Round each component up to the nearest integer
+    pub fn ceil(self) -> Vector<T> {
+        Vector::new(self.x.ceil(), self.y.ceil())
+    }
+
+    /// Round each component to the nearest integer, with halves rounded away from zero
+    pub fn round(self) -> Vector<T> {
+        Vector::new(self.x.round(), self.y.round())
+    }
+
+    /// Raise each component to the power `exp`
+    ///
+    /// Component-wise, unlike `magnitude`; a negative component with a
+    /// non-integer `exp` gives `NaN`.
+    pub fn powf(self, exp: T) -> Vector<T> {
+        Vector::new(self.x.powf(exp), self.y.powf(exp))
+    }
+
+    /// Take the square root of each component; negative components give `NaN`
+    pub fn sqrt(self) -> Vector<T> {
+        Vector::new(self.x.sqrt(), self.y.sqrt())
+    }
+
+    /// Round each component to the nearest multiple of `cell`
+    ///
+    /// A zero `cell` leaves the vector unchanged rather than producing `NaN`.
+    pub fn snap_to_grid(self, cell: T) -> Vector<T> {
+        self.snap_to_grid_v(Vector::new(cell, cell))
+    }
+
+    /// Round to a grid with a separate cell size per axis
+    ///
+    /// As with `snap_to_grid`, an axis with a zero cell size is left unchanged.
+    pub fn snap_to_grid_v(self, cell: Vector<T>) -> Vector<T> {
+        let snap = |value: T, size: T| {
+            if size.is_zero() {
+                value
+            } else {
+                (value / size).round() * size
+            }
+        };
+        Vector::new(snap(self.x, cell.x), snap(self.y, cell.y))
+    }
+}
+
+impl<T: fmt::Display> Vector<T> {
+    /// Format as a CSV row, `"x,y"`
+    ///
+    /// Components use `Display`, which for floats is the shortest string that
+    /// parses back to the same value, so `from_csv_row` round-trips exactly.
+    pub fn to_csv_row(self) -> String {
+        format!("{},{}", self.x, self.y)
+    }
+}
+
+impl<T: core::str::FromStr> Vector<T> {
+    /// Parse a CSV row of the form `"x,y"`, trimming whitespace around each field
+    ///
+    /// Errors if the row doesn't have exactly two columns or a field doesn't parse.
+    pub fn from_csv_row(s: &str) -> Result<Vector<T>, VectorError> {
+        let mut fields = s.split(',');
+        let (Some(x), Some(y), None) = (fields.next(), fields.next(), fields.next()) else {
+            return Err(VectorError::InvalidOperation(format!(
+                "Expected 2 CSV columns, got {}",
+                s.split(',').count()
+            )));
+        };
+        let parse = |field: &str| {
+            field.trim().parse().map_err(|_| {
+                VectorError::InvalidOperation(format!("Invalid CSV field: {:?}", field.trim()))
+            })
+        };
+        Ok(Vector::new(parse(x)?, parse(y)?))
+    }
+}
+
+impl Vector {
+    /// Pack into 16 bytes: `x` then `y`, each as a little-endian `f64`
+    ///
+    /// The layout is fixed and platform-independent, unlike serde formats
+    /// whose encoding depends on their configuration.
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&self.x.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.y.to_le_bytes());
+        bytes
+    }
+
+    /// Unpack a vector written by `to_le_bytes`
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Vector {
+        let (x, y) = bytes.split_at(8);
+        Vector::new(
+            f64::from_le_bytes(x.try_into().unwrap()),
+            f64::from_le_bytes(y.try_into().unwrap()),
+        )
+    }
+}
+
+/// Tolerance used by `ApproxEq::approx_eq_default`
+pub const DEFAULT_EPSILON: f64 = 1e-9;
+
+/// Approximate equality for floating point values
+pub trait ApproxEq: Sized {
+    /// Check equality to within `epsilon` (per component for vectors)
+    fn approx_eq_within(self, other: Self, epsilon: f64) -> bool;
+
+    /// Check equality to within `DEFAULT_EPSILON`
+    fn approx_eq_default(self, other: Self) -> bool {
+        self.approx_eq_within(other, DEFAULT_EPSILON)
+    }
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq_within(self, other: f64, epsilon: f64) -> bool {
+        Float::abs(self - other) < epsilon
+    }
+}
+
+impl ApproxEq for Vector {
+    fn approx_eq_within(self, other: Vector, epsilon: f64) -> bool {
+        self.approx_eq(other, epsilon)
+    }
+}
+
+/// A `Vector` wrapper that implements `Hash` and `Eq`, for use as a map or set key
+///
+/// Equality and hashing compare the bit patterns of the components
+/// (`f64::to_bits`) rather than their numeric values. As a result a `NaN`
+/// component equals itself when the bits match, and `0.0` and `-0.0` are
+/// different keys. This suits grid-aligned points produced the same way.
+#[derive(Debug, Clone, Copy)]
+pub struct HashableVector(pub Vector);
+
+impl HashableVector {
+    fn bits(self) -> (u64, u64) {
+        (self.0.x.to_bits(), self.0.y.to_bits())
+    }
+}
+
+impl PartialEq for HashableVector {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for HashableVector {}
+
+impl Hash for HashableVector {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+impl From<Vector> for HashableVector {
+    fn from(v: Vector) -> Self {
+        HashableVector(v)
+    }
+}
+
+/// Compute element-wise dot products of two equally sized slices of vectors
+///
+/// The loop zips the slices so the compiler can drop bounds checks and
+/// autovectorize it. Panics if the slices differ in length.
+pub fn dot_batch<T: Num + Copy>(a: &[Vector<T>], b: &[Vector<T>]) -> Vec<T> {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "dot_batch requires slices of equal length"
+    );
+    a.iter().zip(b).map(|(&u, &v)| u.dot(v)).collect()
+}
+
+/// Check that two slices of vectors match pairwise to within `epsilon`
+///
+/// Slices of different lengths never match. Each pair is compared with `approx_eq`.
+pub fn slices_approx_eq<T: Float>(a: &[Vector<T>], b: &[Vector<T>], epsilon: T) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(&u, &v)| u.approx_eq(v, epsilon))
+}
+
+/// Arithmetic mean of a set of points, or `None` for an empty slice
+pub fn centroid<T: Float>(points: &[Vector<T>]) -> Option<Vector<T>> {
+    if points.is_empty() {
+        return None;
+    }
+    let count = T::from(points.len())?;
+    Some(points.iter().sum::<Vector<T>>() / count)
+}
+
+/// Compute the axis-aligned bounding box of a set of points
+///
+/// Returns `(min_corner, max_corner)`, or `None` for an empty slice.
+pub fn bounding_box<T: Float>(points: &[Vector<T>]) -> Option<(Vector<T>, Vector<T>)> {
+    let (&first, rest) = points.split_first()?;
+    Some(rest.iter().fold((first, first), |(min, max), &point| {
+        (min.min_components(point), max.max_components(point))
+    }))
+}
+
+/// Turn direction of three points, as reported by `orientation`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// `a -> b -> c` turns right
+    Clockwise,
+    /// `a -> b -> c` turns left
+    CounterClockwise,
+    /// The points lie on one line (or coincide)
+    Collinear,
+}
+
+/// Classify the turn `a -> b -> c` from the sign of `(b - a).cross(c - a)`
+///
+/// Cross products within `T::epsilon() * |b - a| * |c - a|` of zero count as
+/// collinear, so the result doesn't depend on the scale of the input.
+pub fn orientation<T: Float>(a: Vector<T>, b: Vector<T>, c: Vector<T>) -> Orientation {
+    let (ab, ac) = (b - a, c - a);
+    let cross = ab.cross(ac);
+    let tolerance = T::epsilon() * ab.magnitude() * ac.magnitude();
+    if cross > tolerance {
+        Orientation::CounterClockwise
+    } else if cross < -tolerance {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+/// Area of the triangle `abc`: half the absolute cross product of two edges
+pub fn triangle_area<T: Float>(a: Vector<T>, b: Vector<T>, c: Vector<T>) -> T {
+    (b - a).cross(c - a).abs() / (T::one() + T::one())
+}
+
+/// Unsigned area of a polygon using the shoelace formula
+///
+/// Vertices may wind either way and the closing edge back to the first vertex
+/// is implied. They are assumed to form a simple (non-self-intersecting)
+/// polygon; otherwise oppositely wound lobes cancel out. Fewer than 3 vertices
+/// give zero.
+pub fn polygon_area<T: Float>(vertices: &[Vector<T>]) -> T {
+    let next = vertices.iter().cycle().skip(1);
+    let twice_signed = vertices
+        .iter()
+        .zip(next)
+        .fold(T::zero(), |sum, (&a, &b)| sum + a.cross(b));
+    twice_signed.abs() / (T::one() + T::one())
+}
+
+/// Compute the convex hull of a set of points with Andrew's monotone chain
+///
+/// Returns the hull vertices in counter-clockwise order, starting from the
+/// lowest-x (then lowest-y) point, without repeating the first vertex at the
+/// end. Duplicate points and points lying on a hull edge are dropped. With
+/// fewer than 3 distinct points the distinct points are returned as-is, and an
+/// all-collinear input yields its two extreme points.
+pub fn convex_hull<T: Float>(points: &[Vector<T>]) -> Vec<Vector<T>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(core::cmp::Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(core::cmp::Ordering::Equal))
+    });
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    fn half_hull<'a, T: Float + 'a>(
+        points: impl Iterator<Item = &'a Vector<T>>,
+    ) -> Vec<Vector<T>> {
+        let mut chain: Vec<Vector<T>> = Vec::new();
+        for &point in points {
+            while chain.len() >= 2
+                && orientation(chain[chain.len() - 2], chain[chain.len() - 1], point)
+                    != Orientation::CounterClockwise
+            {
+                chain.pop();
+            }
+            chain.push(point);
+        }
+        // The last point starts the other half
+        chain.pop();
+        chain
+    }
+
+    let mut hull = half_hull(sorted.iter());
+    hull.extend(half_hull(sorted.iter().rev()));
+    hull
+}
+
+/// Serde helpers representing a `Vector` as a two-element `[x, y]` array
+///
+/// The derived representation (`{"x": .., "y": ..}`) stays the default; opt in
+/// per field with `#[serde(with = "fluent_python_rs::ch01_data_model::vector::as_array")]`.
+pub mod as_array {
+    use super::Vector;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialize a vector as `[x, y]`
+    pub fn serialize<T: Serialize, S: Serializer>(
+        vector: &Vector<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        [&vector.x, &vector.y].serialize(serializer)
+    }
+
+    /// Deserialize a vector from `[x, y]`
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vector<T>, D::Error> {
+        <[T; 2]>::deserialize(deserializer).map(Vector::from)
+    }
+}
+
+/// A 3D vector with x, y and z components
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Vector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vector3 {
+    /// Create a new 3D vector
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Create a zero vector
+    pub fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    /// Calculate the magnitude (length) of the vector
+    pub fn magnitude(self) -> f64 {
+        Float::sqrt(self.magnitude_squared())
+    }
+
+    /// Calculate the squared magnitude (avoiding sqrt for performance)
+    pub fn magnitude_squared(self) -> f64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Normalize the vector to unit length
+    pub fn normalized(self) -> Result<Vector3, VectorError> {
+        let mag = self.magnitude();
+        if mag == 0.0 {
+            Err(VectorError::ZeroVectorNormalization)
+        } else {
+            Ok(Vector3::new(self.x / mag, self.y / mag, self.z / mag))
+        }
+    }
+
+    /// Calculate dot product with another vector
+    pub fn dot(self, other: Vector3) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Calculate the cross product with another vector
+    ///
+    /// Unlike the 2D `Vector::cross`, which returns only the scalar z-component,
+    /// this returns a full `Vector3` perpendicular to both inputs, following the
+    /// right-hand rule (`x × y = z`).
+    pub fn cross(self, other: Vector3) -> Vector3 {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Calculate distance to another vector
+    pub fn distance_to(self, other: Vector3) -> f64 {
+        (other - self).magnitude()
+    }
+}
+
+impl fmt::Display for Vector3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Vector3({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, rhs: Vector3) -> Vector3 {
+        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, rhs: Vector3) -> Vector3 {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Mul<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, scalar: f64) -> Vector3 {
+        Vector3::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Default for Vector3 {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-10;
+
+    #[test]
+    fn test_vector_creation() {
+        let v = Vector::new(3.0, 4.0);
+        assert_eq!(v.x, 3.0);
+        assert_eq!(v.y, 4.0);
+        assert_eq!(v.to_string(), "Vector(3, 4)");
+    }
+
+    #[test]
+    fn test_vector_magnitude() {
+        let v = Vector::new(3.0, 4.0);
+        assert_eq!(v.magnitude(), 5.0);
+        assert_eq!(v.magnitude_squared(), 25.0);
+        
+        let zero = Vector::zero();
+        assert_eq!(zero.magnitude(), 0.0);
+        assert!(zero.is_zero());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_random_unit() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let samples: Vec<Vector> = (0..10_000).map(|_| Vector::random_unit(&mut rng)).collect();
+
+        let mean_magnitude =
+            samples.iter().map(|v| v.magnitude()).sum::<f64>() / samples.len() as f64;
+        let mean = samples.iter().sum::<Vector>() / samples.len() as f64;
+
+        assert!((mean_magnitude - 1.0).abs() < EPSILON);
+        assert!(mean.x.abs() < 0.05 && mean.y.abs() < 0.05);
+        assert!((Vector::random_unit_thread_rng().magnitude() - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_validity_checks() {
+        assert!(Vector::new(3.0, 4.0).is_finite());
+        assert!(!Vector::new(3.0, 4.0).is_nan());
+
+        let nan = Vector::new(f64::NAN, 1.0);
+        assert!(nan.is_nan());
+        assert!(!nan.is_finite());
+
+        let infinite = Vector::new(1.0, f64::INFINITY);
+        assert!(!infinite.is_nan());
+        assert!(!infinite.is_finite());
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let huge = Vector::new(f64::MAX, 1.0);
+        assert_eq!(huge.checked_add(huge), None);
+        assert_eq!(huge.checked_mul(2.0), None);
+
+        let v = Vector::new(1.0, 2.0);
+        assert_eq!(v.checked_add(Vector::new(3.0, 4.0)), Some(Vector::new(4.0, 6.0)));
+        assert_eq!(v.checked_mul(-2.0), Some(Vector::new(-2.0, -4.0)));
+
+        // Only overflow is caught; already non-finite inputs behave like the operators
+        let infinite = Vector::new(f64::INFINITY, 0.0);
+        assert_eq!(infinite.checked_add(v), Some(infinite + v));
+        assert_eq!(v.checked_mul(f64::INFINITY), Some(v * f64::INFINITY));
+    }
+
+    #[test]
+    fn test_vector_arithmetic() {
+        let v1 = Vector::new(1.0, 2.0);
+        let v2 = Vector::new(3.0, 4.0);
+        
+        assert_eq!(v1 + v2, Vector::new(4.0, 6.0));
+        assert_eq!(v2 - v1, Vector::new(2.0, 2.0));
+        assert_eq!(v1 * 2.0, Vector::new(2.0, 4.0));
+        assert_eq!(2.0 * v1, Vector::new(2.0, 4.0));
+        assert_eq!(-v1, Vector::new(-1.0, -2.0));
+    }
+
+    #[test]
+    fn test_vector_normalization() {
+        let v = Vector::new(3.0, 4.0);
+        let normalized = v.normalized().unwrap();
+        assert!(normalized.magnitude().abs() - 1.0 < EPSILON);
+        
+        let zero = Vector::zero();
+        assert!(zero.normalized().is_err());
+
+        assert_eq!(v.normalized_or_zero(), normalized);
+        assert_eq!(v.normalized_or(Vector::unit_y()), normalized);
+        assert_eq!(zero.normalized_or_zero(), zero);
+        assert_eq!(zero.normalized_or(Vector::unit_y()), Vector::unit_y());
+    }
+
+    #[test]
+    fn test_clamp_magnitude() {
+        let v = Vector::new(3.0, 4.0);
+        assert!(v.clamp_magnitude(2.5).approx_eq(Vector::new(1.5, 2.0), EPSILON));
+
+        // Already short enough: returned untouched
+        assert_eq!(v.clamp_magnitude(5.0), v);
+        assert_eq!(v.clamp_magnitude(10.0), v);
+        assert_eq!(Vector::zero().clamp_magnitude(1.0), Vector::zero());
+    }
+
+    #[test]
+    fn test_with_magnitude() {
+        let v = Vector::new(3.0, 4.0);
+        assert!(v.with_magnitude(10.0).unwrap().approx_eq(Vector::new(6.0, 8.0), EPSILON));
+        assert_eq!(
+            Vector::zero().with_magnitude(1.0),
+            Err(VectorError::ZeroVectorNormalization)
+        );
+    }
+
+    #[test]
+    fn test_dot_and_cross_product() {
+        let v1 = Vector::new(1.0, 2.0);
+        let v2 = Vector::new(3.0, 4.0);
+        
+        assert_eq!(v1.dot(v2), 11.0); // 1*3 + 2*4 = 11
+        assert_eq!(v1.cross(v2), -2.0); // 1*4 - 2*3 = -2
+    }
+
+    #[test]
+    fn test_dot_batch() {
+        let a: Vec<Vector> = (0..1000).map(|i| Vector::new(i as f64, 0.5 * i as f64)).collect();
+        let b: Vec<Vector> = (0..1000).map(|i| Vector::new(-0.25 * i as f64, 3.0)).collect();
+
+        let naive: Vec<f64> = a.iter().zip(&b).map(|(u, v)| u.dot(*v)).collect();
+        assert_eq!(dot_batch(&a, &b), naive);
+        assert!(dot_batch::<f64>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "dot_batch requires slices of equal length")]
+    fn test_dot_batch_length_mismatch() {
+        dot_batch(&[Vector::unit_x()], &[]);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [
+            Vector::new(1.0, 5.0),
+            Vector::new(-2.0, 3.0),
+            Vector::new(4.0, -1.0),
+            Vector::new(0.5, 7.5),
+        ];
+        assert_eq!(
+            bounding_box(&points),
+            Some((Vector::new(-2.0, -1.0), Vector::new(4.0, 7.5)))
+        );
+        assert_eq!(
+            bounding_box(&points[..1]),
+            Some((Vector::new(1.0, 5.0), Vector::new(1.0, 5.0)))
+        );
+        assert_eq!(bounding_box::<f64>(&[]), None);
+
+        // A NaN coordinate is ignored wherever its point sits
+        let expected = Some((Vector::new(-2.0, -1.0), Vector::new(4.0, 7.5)));
+        let nan = Vector::new(f64::NAN, 0.0);
+        assert_eq!(bounding_box(&[nan, points[0], points[1], points[2], points[3]]), expected);
+        assert_eq!(bounding_box(&[points[0], points[1], points[2], points[3], nan]), expected);
+    }
+
+    #[test]
+    fn test_distance() {
+        let v1 = Vector::new(0.0, 0.0);
+        let v2 = Vector::new(3.0, 4.0);
+        
+        assert_eq!(v1.distance_to(v2), 5.0);
+        assert_eq!(v1.distance_squared_to(v2), 25.0);
+    }
+
+    #[test]
+    fn test_grid_distances() {
+        let v1 = Vector::new(1.0, 1.0);
+        let v2 = Vector::new(4.0, 5.0);
+
+        assert_eq!(v1.distance_to(v2), 5.0);
+        assert_eq!(v1.manhattan_distance_to(v2), 7.0);
+        assert_eq!(v1.chebyshev_distance_to(v2), 4.0);
+        assert_eq!(v2.manhattan_distance_to(v1), 7.0);
+        assert_eq!(v2.chebyshev_distance_to(v1), 4.0);
+
+        let tile: Vector<i32> = Vector::new(-2, 3);
+        assert_eq!(tile.manhattan_distance_to(Vector::new(1, -1)), 7);
+        assert_eq!(tile.chebyshev_distance_to(Vector::new(1, -1)), 4);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let v = Vector::new(1.0, 0.0);
+        let rotated = v.rotated(std::f64::consts::PI / 2.0); // 90 degrees
+        
+        assert!(rotated.approx_eq(Vector::new(0.0, 1.0), EPSILON));
+    }
+
+    #[test]
+    fn test_angle_with_nearly_parallel_vectors() {
+        // The dot/magnitude ratio of this pair rounds to just above 1.0
+        let v = Vector::new(0.2, 0.44).normalized().unwrap();
+        let angle = v.angle_with(v).unwrap();
+        assert!(!angle.is_nan());
+        assert!(angle.abs() < EPSILON);
+
+        let opposite = v.angle_with(-v).unwrap();
+        assert!((opposite - std::f64::consts::PI).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_signed_angle() {
+        use std::f64::consts::PI;
+
+        let x = Vector::unit_x();
+        let y = Vector::unit_y();
+
+        assert!((x.signed_angle_to(y) - PI / 2.0).abs() < EPSILON);
+        assert!((y.signed_angle_to(x) + PI / 2.0).abs() < EPSILON);
+        assert!((x.signed_angle_to(y * 5.0) - PI / 2.0).abs() < EPSILON);
+        assert_eq!(x.signed_angle_to(-x), PI);
+        assert_eq!(x.signed_angle_to(Vector::zero()), 0.0);
+    }
+
+    #[test]
+    fn test_degree_variants() {
+        let angle = Vector::unit_x().angle_with_degrees(Vector::unit_y()).unwrap();
+        assert!((angle - 90.0).abs() < EPSILON);
+
+        let rotated = Vector::unit_x().rotated_degrees(90.0);
+        assert!(rotated.approx_eq(Vector::unit_y(), EPSILON));
+
+        assert!(Vector::unit_x().angle_with_degrees(Vector::zero()).is_err());
+    }
+
+    #[test]
+    fn test_angle() {
+        use std::f64::consts::PI;
+
+        assert_eq!(Vector::unit_x().angle(), 0.0);
+        assert!((Vector::unit_y().angle() - PI / 2.0).abs() < EPSILON);
+        assert_eq!(Vector::new(-1.0, 0.0).angle(), PI);
+        assert_eq!(Vector::new(-1.0, -0.0).angle(), PI);
+        assert!((Vector::new(1.0, -1.0).angle() + PI / 4.0).abs() < EPSILON);
+        assert_eq!(Vector::zero().angle(), 0.0);
+        assert_eq!(Vector::new(-0.0, -0.0).angle(), 0.0);
+
+        let v = Vector::from_polar(2.0, 2.5);
+        assert!((v.angle() - 2.5).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_heading_degrees() {
+        assert_eq!(Vector::up().heading_degrees(), 0.0);
+        assert_eq!(Vector::right().heading_degrees(), 90.0);
+        assert_eq!(Vector::down().heading_degrees(), 180.0);
+        assert_eq!(Vector::left().heading_degrees(), 270.0);
+        assert!((Vector::new(-1.0, 1.0).heading_degrees() - 315.0).abs() < EPSILON);
+        assert_eq!(Vector::new(-0.0, 1.0).heading_degrees(), 0.0);
+        assert_eq!(Vector::zero().heading_degrees(), 0.0);
+
+        let just_west_of_north = Vector::new(-1e-300, 1.0).heading_degrees();
+        assert!((0.0..360.0).contains(&just_west_of_north));
+    }
+
+    #[test]
+    fn test_polar_round_trip() {
+        use std::f64::consts::PI;
+
+        for &(r, theta) in &[(1.0, 0.3), (2.0, 2.0), (3.5, -2.5), (0.5, -0.7), (4.0, PI)] {
+            let (magnitude, angle) = Vector::from_polar(r, theta).to_polar();
+            assert!((magnitude - r).abs() < EPSILON);
+            assert!((angle - theta).abs() < EPSILON);
+        }
+
+        assert!(Vector::from_polar(2.0, PI / 2.0).approx_eq(Vector::new(0.0, 2.0), EPSILON));
+        assert_eq!(Vector::new(-1.0, -0.0).to_polar(), (1.0, PI));
+        assert_eq!(Vector::zero().to_polar(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_quarter_turn_rotations() {
+        use std::f64::consts::PI;
+
+        let v = Vector::new(1.0, 2.0);
+        assert_eq!(v.rotate_90(), v.perpendicular());
+        assert_eq!(v.rotate_180(), Vector::new(-1.0, -2.0));
+        assert_eq!(v.rotate_270(), Vector::new(2.0, -1.0));
+        assert_eq!(v.rotate_90().rotate_270(), v);
+
+        assert!(v.rotate_90().approx_eq(v.rotated(PI / 2.0), EPSILON));
+        assert!(v.rotate_180().approx_eq(v.rotated(PI), EPSILON));
+        assert!(v.rotate_270().approx_eq(v.rotated(3.0 * PI / 2.0), EPSILON));
+
+        assert_eq!(Vector::new(3, -1).rotate_90(), Vector::new(1, 3));
+    }
+
+    #[test]
+    fn test_transform_by_basis() {
+        use std::f64::consts::PI;
+
+        let v = Vector::new(3.0, -2.0);
+        let quarter_turn = [Vector::unit_y(), -Vector::unit_x()];
+        assert!(v.transform(quarter_turn).approx_eq(v.rotated(PI / 2.0), EPSILON));
+        assert_eq!(v.transform(quarter_turn), v.rotate_90());
+
+        assert_eq!(v.transform([Vector::unit_x(), Vector::unit_y()]), v);
+        let shear = [Vector::new(1.0, 0.0), Vector::new(1.0, 1.0)];
+        assert_eq!(v.transform(shear), Vector::new(1.0, -2.0));
+        let shear_matrix = crate::ch01_data_model::matrix::Matrix2::new(1.0, 1.0, 0.0, 1.0);
+        assert_eq!(v.transform(shear), shear_matrix * v);
+    }
+
+    #[test]
+    fn test_rotate_around_pivot() {
+        let pivot = Vector::new(2.0, 3.0);
+        let point = Vector::new(5.0, 4.0);
+
+        let half_turn = point.rotate_around(pivot, std::f64::consts::PI);
+        assert!(half_turn.approx_eq(Vector::new(-1.0, 2.0), EPSILON));
+
+        let quarter_turn = point.rotate_around(pivot, std::f64::consts::PI / 2.0);
+        assert!(quarter_turn.approx_eq(Vector::new(1.0, 6.0), EPSILON));
+        assert!(pivot.rotate_around(pivot, 1.0).approx_eq(pivot, EPSILON));
+    }
+
+    #[test]
+    fn test_projection() {
+        let v1 = Vector::new(2.0, 3.0);
+        let v2 = Vector::new(1.0, 0.0); // Unit vector in x direction
+        
+        let projected = v1.project_onto(v2).unwrap();
+        assert_eq!(projected, Vector::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn test_decompose() {
+        let v = Vector::new(2.0, 3.0);
+        let (parallel, perpendicular) = v.decompose(Vector::unit_x()).unwrap();
+        assert_eq!(parallel, Vector::new(2.0, 0.0));
+        assert_eq!(perpendicular, Vector::new(0.0, 3.0));
+        assert_eq!(parallel + perpendicular, v);
+
+        let (parallel, perpendicular) = v.decompose(Vector::new(1.0, 1.0)).unwrap();
+        assert!((parallel + perpendicular).approx_eq(v, EPSILON));
+        assert!(perpendicular.dot(Vector::new(1.0, 1.0)).abs() < EPSILON);
+
+        assert_eq!(v.decompose(Vector::zero()), Err(v.project_onto(Vector::zero()).unwrap_err()));
+    }
+
+    #[test]
+    fn test_perpendicular() {
+        let v = Vector::new(1.0, 2.0);
+        let perp = v.perpendicular();
+        
+        assert_eq!(perp, Vector::new(-2.0, 1.0));
+        assert_eq!(v.dot(perp), 0.0); // Should be orthogonal
+    }
+
+    #[test]
+    fn test_reflect() {
+        let v = Vector::new(1.0, -1.0);
+        assert_eq!(v.reflect(Vector::unit_y()).unwrap(), Vector::new(1.0, 1.0));
+        assert_eq!(v.reflect_unchecked(Vector::unit_y()), Vector::new(1.0, 1.0));
+
+        // Non-normalized normals are accepted by the checked version
+        assert!(v
+            .reflect(Vector::new(0.0, 3.0))
+            .unwrap()
+            .approx_eq(Vector::new(1.0, 1.0), EPSILON));
+        assert!(v.reflect(Vector::zero()).is_err());
+    }
+
+    #[test]
+    fn test_reflect_across_line() {
+        let v = Vector::new(1.0, 1.0);
+        assert_eq!(v.reflect_across_line(Vector::unit_x()).unwrap(), Vector::new(1.0, -1.0));
+        assert_eq!(v.reflect_across_line(Vector::unit_y()).unwrap(), Vector::new(-1.0, 1.0));
+
+        // Across the diagonal y = x the components swap, whatever the direction's length
+        let p = Vector::new(3.0, -2.0);
+        assert!(p
+            .reflect_across_line(Vector::new(-4.0, -4.0))
+            .unwrap()
+            .approx_eq(p.yx(), EPSILON));
+
+        // Across a line vs. off a surface with the same vector as normal
+        assert_eq!(
+            v.reflect_across_line(Vector::unit_y()).unwrap(),
+            -v.reflect(Vector::unit_y()).unwrap()
+        );
+        assert_eq!(
+            v.reflect_across_line(Vector::zero()),
+            Err(VectorError::InvalidOperation("Cannot reflect across a zero direction".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_lerp_polar() {
+        use std::f64::consts::PI;
+
+        let x = Vector::unit_x();
+        let up_two = Vector::new(0.0, 2.0);
+        let mid = x.lerp_polar(up_two, 0.5).unwrap();
+        assert!((mid.magnitude() - 1.5).abs() < EPSILON);
+        assert!((mid.to_polar().1 - PI / 4.0).abs() < EPSILON);
+        assert!(x.lerp_polar(up_two, 1.0).unwrap().approx_eq(up_two, EPSILON));
+        assert!(x.lerp_polar(up_two, 2.0).unwrap().approx_eq(up_two, EPSILON));
+
+        // Crossing the ±π seam takes the short way round through the negative x axis
+        let from = Vector::from_polar(1.0, 170f64.to_radians());
+        let to = Vector::from_polar(1.0, -170f64.to_radians());
+        assert!(from.lerp_polar(to, 0.5).unwrap().approx_eq(-x, EPSILON));
+
+        assert_eq!(
+            x.lerp_polar(Vector::zero(), 0.5),
+            Err(x.angle_with(Vector::zero()).unwrap_err())
+        );
+    }
+
+    #[test]
+    fn test_parallel_and_perpendicular() {
+        let x = Vector::unit_x();
+        let y = Vector::unit_y();
+
+        assert!(x.is_perpendicular_to(y, EPSILON));
+        assert!(!x.is_parallel_to(y, EPSILON));
+
+        assert!(x.is_parallel_to(Vector::new(2.0, 0.0), EPSILON));
+        assert!(x.is_parallel_to(Vector::new(-3.0, 0.0), EPSILON));
+        assert!(!x.is_perpendicular_to(Vector::new(2.0, 0.0), EPSILON));
+
+        let zero = Vector::zero();
+        assert!(zero.is_parallel_to(x, EPSILON));
+        assert!(zero.is_perpendicular_to(x, EPSILON));
+    }
+
+    #[test]
+    fn test_move_towards() {
+        let start = Vector::zero();
+        let target = Vector::new(3.0, 4.0);
+
+        let step = start.move_towards(target, 2.0);
+        assert!(step.approx_eq(Vector::new(1.2, 1.6), EPSILON));
+        assert!((step.distance_to(start) - 2.0).abs() < EPSILON);
+
+        assert_eq!(start.move_towards(target, 5.0), target);
+        assert_eq!(start.move_towards(target, 10.0), target);
+        assert_eq!(step.move_towards(target, 3.0).move_towards(target, 3.0), target);
+        assert_eq!(target.move_towards(target, 0.0), target);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let v1 = Vector::new(0.0, 0.0);
+        let v2 = Vector::new(10.0, 20.0);
+        
+        assert_eq!(v1.lerp(v2, 0.0), v1);
         assert_eq!(v1.lerp(v2, 1.0), v2);
         assert_eq!(v1.lerp(v2, 0.5), Vector::new(5.0, 10.0));
+        assert_eq!(v1.lerp_unclamped(v2, 0.5), v1.lerp(v2, 0.5));
+
+        // Outside [0, 1] the two diverge: lerp clamps, lerp_unclamped extrapolates
+        assert_eq!(v1.lerp(v2, 1.5), v2);
+        assert_eq!(v1.lerp_unclamped(v2, 1.5), Vector::new(15.0, 30.0));
+        assert_eq!(v1.lerp(v2, -0.5), v1);
+        assert_eq!(v1.lerp_unclamped(v2, -0.5), Vector::new(-5.0, -10.0));
+    }
+
+    #[test]
+    fn test_midpoint_and_weighted_average() {
+        let a = Vector::new(0.0, 0.0);
+        let b = Vector::new(4.0, -2.0);
+        assert_eq!(a.midpoint(b), Vector::new(2.0, -1.0));
+
+        let c = Vector::new(2.0, 5.0);
+        let centroid = Vector::weighted_average(&[(a, 1.0), (b, 1.0), (c, 1.0)]).unwrap();
+        assert!(centroid.approx_eq(Vector::new(2.0, 1.0), EPSILON));
+
+        let biased = Vector::weighted_average(&[(a, 3.0), (b, 1.0)]).unwrap();
+        assert_eq!(biased, Vector::new(1.0, -0.5));
+
+        assert!(Vector::weighted_average(&[(a, 1.0), (b, -1.0)]).is_err());
+        assert!(Vector::<f64>::weighted_average(&[]).is_err());
+    }
+
+    #[test]
+    fn test_slerp() {
+        let x = Vector::unit_x();
+        let y = Vector::unit_y();
+
+        let mid = x.slerp(y, 0.5).unwrap();
+        assert!(mid.magnitude() >= 1.0 - EPSILON && mid.magnitude() <= 1.0 + EPSILON);
+        assert!((mid.angle_with_degrees(x).unwrap() - 45.0).abs() < EPSILON);
+
+        assert!(x.slerp(y, 0.0).unwrap().approx_eq(x, EPSILON));
+        assert!(x.slerp(y, 1.0).unwrap().approx_eq(y, EPSILON));
+
+        // Parallel inputs fall back to lerp
+        assert_eq!(x.slerp(x * 3.0, 0.5).unwrap(), x.lerp_unclamped(x * 3.0, 0.5));
+        assert!(x.slerp(Vector::zero(), 0.5).is_err());
     }
 
     #[test]
@@ -347,10 +1791,56 @@ mod tests {
         let v2 = Vector::new(4.0, 5.0);
         
         assert_eq!(v1.component_mul(v2), Vector::new(8.0, 15.0));
+        assert_eq!(v1 * v2, v1.component_mul(v2));
+        assert_eq!(Vector::new(2, 3) * Vector::new(4, 5), Vector::new(8, 15));
+        assert_eq!(v1.scale_non_uniform(2.0, 0.5), Vector::new(4.0, 1.5));
+        assert_eq!(v1.scale_non_uniform(4.0, 5.0), v1.component_mul(v2));
+
+        let v = Vector::new(1.0, 2.0);
+        assert_eq!(v.yx(), Vector::new(2.0, 1.0));
+        assert_eq!(v.xx(), Vector::new(1.0, 1.0));
+        assert_eq!(v.yy(), Vector::new(2.0, 2.0));
+        assert_eq!(v.yx().yx(), v);
         assert_eq!(v1.component_div(v2).unwrap(), Vector::new(0.5, 0.6));
         
         assert_eq!(v1.min_components(v2), Vector::new(2.0, 3.0));
         assert_eq!(v1.max_components(v2), Vector::new(4.0, 5.0));
+
+        // NaN loses on either side, matching f64::min and f64::max
+        let nan = Vector::new(f64::NAN, 1.0);
+        assert_eq!(nan.min_components(v2), Vector::new(4.0, 1.0));
+        assert_eq!(v2.min_components(nan), Vector::new(4.0, 1.0));
+        assert_eq!(nan.max_components(v2), Vector::new(4.0, 5.0));
+        assert_eq!(v2.max_components(nan), Vector::new(4.0, 5.0));
+    }
+
+    #[test]
+    fn test_component_rounding() {
+        assert_eq!(Vector::new(-1.5, 2.0).abs(), Vector::new(1.5, 2.0));
+        assert_eq!(Vector::new(-3.0, -0.25).abs(), Vector::new(3.0, 0.25));
+
+        let v = Vector::new(1.7, -1.2);
+        assert_eq!(v.floor(), Vector::new(1.0, -2.0));
+        assert_eq!(v.ceil(), Vector::new(2.0, -1.0));
+        assert_eq!(v.round(), Vector::new(2.0, -1.0));
+
+        // Halves round away from zero
+        assert_eq!(Vector::new(2.5, -2.5).round(), Vector::new(3.0, -3.0));
+
+        let v = Vector::new(1.2, 3.7);
+        assert_eq!(v.snap_to_grid(1.0), Vector::new(1.0, 4.0));
+        assert_eq!(v.snap_to_grid(0.5), Vector::new(1.0, 3.5));
+        assert_eq!(Vector::new(-7.0, 13.0).snap_to_grid(4.0), Vector::new(-8.0, 12.0));
+        assert_eq!(v.snap_to_grid_v(Vector::new(2.0, 0.25)), Vector::new(2.0, 3.75));
+        assert_eq!(v.snap_to_grid(0.0), v);
+        assert_eq!(v.snap_to_grid_v(Vector::new(0.0, 1.0)), Vector::new(1.2, 4.0));
+
+        assert_eq!(Vector::new(4.0, 9.0).sqrt(), Vector::new(2.0, 3.0));
+        assert!(Vector::new(-4.0, 9.0).sqrt().x.is_nan());
+        assert_eq!(Vector::new(3.0, -2.0).powf(2.0), Vector::new(9.0, 4.0));
+        let u = Vector::new(8.0, 0.25);
+        assert!(u.powf(0.5).approx_eq(u.sqrt(), EPSILON));
+        assert!(Vector::new(-8.0, 1.0).powf(0.5).is_nan());
     }
 
     #[test]
@@ -358,6 +1848,12 @@ mod tests {
         assert_eq!(Vector::zero(), Vector::new(0.0, 0.0));
         assert_eq!(Vector::unit_x(), Vector::new(1.0, 0.0));
         assert_eq!(Vector::unit_y(), Vector::new(0.0, 1.0));
+
+        assert_eq!(Vector::right(), Vector::unit_x());
+        assert_eq!(Vector::up(), Vector::new(0.0, 1.0));
+        assert_eq!(Vector::left(), Vector::new(-1.0, 0.0));
+        assert_eq!(Vector::down(), Vector::new(0.0, -1.0));
+        assert_eq!(Vector::right().rotate_90(), Vector::up());
     }
 
     #[test]
@@ -372,4 +1868,414 @@ mod tests {
         let div_by_zero = Vector::new(1.0, 0.0);
         assert!(v.component_div(div_by_zero).is_err());
     }
+
+    #[test]
+    fn test_compound_assignment() {
+        let delta = Vector::new(0.5, -1.0);
+        let mut accumulated = Vector::new(1.0, 2.0);
+        for _ in 0..4 {
+            accumulated += delta;
+        }
+        assert_eq!(accumulated, Vector::new(1.0, 2.0) + delta + delta + delta + delta);
+
+        accumulated -= delta;
+        assert_eq!(accumulated, Vector::new(1.0, 2.0) + delta + delta + delta);
+
+        accumulated *= 2.0;
+        assert_eq!(accumulated, (Vector::new(1.0, 2.0) + delta + delta + delta) * 2.0);
+    }
+
+    #[test]
+    fn test_scalar_division() {
+        assert_eq!(Vector::new(10.0, 20.0) / 2.0, Vector::new(5.0, 10.0));
+
+        let mut v = Vector::new(10.0, 20.0);
+        v /= 4.0;
+        assert_eq!(v, Vector::new(2.5, 5.0));
+
+        let by_zero = Vector::new(1.0, 0.0) / 0.0;
+        assert_eq!(by_zero.x, f64::INFINITY);
+        assert!(by_zero.y.is_nan());
+    }
+
+    #[test]
+    fn test_sum() {
+        let points = vec![
+            Vector::new(1.0, 2.0),
+            Vector::new(3.0, -4.0),
+            Vector::new(0.5, 6.0),
+        ];
+        let total = points[0] + points[1] + points[2];
+
+        assert_eq!(points.iter().sum::<Vector>(), total);
+        assert_eq!(points.clone().into_iter().sum::<Vector>(), total);
+        assert_eq!(Vec::<Vector>::new().into_iter().sum::<Vector>(), Vector::zero());
+
+        let centroid = points.iter().sum::<Vector>() / points.len() as f64;
+        assert!(centroid.approx_eq(Vector::new(1.5, 4.0 / 3.0), EPSILON));
+    }
+
+    #[test]
+    fn test_component_indexing() {
+        let mut v = Vector::new(3.0, 4.0);
+        assert_eq!(v[0], v.x);
+        assert_eq!(v[1], v.y);
+
+        v[1] = 9.0;
+        assert_eq!(v, Vector::new(3.0, 9.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector index out of range: 2")]
+    fn test_component_index_out_of_range() {
+        let v = Vector::new(3.0, 4.0);
+        let _ = v[2];
+    }
+
+    #[test]
+    fn test_slice_and_array_conversions() {
+        let row = [3.0, 4.0];
+        assert_eq!(Vector::try_from(&row[..]), Ok(Vector::new(3.0, 4.0)));
+        assert_eq!(Vector::from(row), Vector::new(3.0, 4.0));
+
+        let too_long = [1.0, 2.0, 3.0];
+        assert_eq!(
+            Vector::try_from(&too_long[..]),
+            Err(VectorError::InvalidOperation(
+                "Expected 2 components, got 3".to_string()
+            ))
+        );
+        assert!(Vector::<f64>::try_from(&[][..]).is_err());
+    }
+
+    #[test]
+    fn test_into_conversions() {
+        let v: Vector = (3.0, 4.0).into();
+        assert_eq!(v, Vector::new(3.0, 4.0));
+        let v: Vector = [3.0, 4.0].into();
+        assert_eq!(v, Vector::new(3.0, 4.0));
+
+        let tuple: (f64, f64) = v.into();
+        assert_eq!(tuple, (3.0, 4.0));
+        assert_eq!(tuple, v.as_tuple());
+        let array: [f64; 2] = v.into();
+        assert_eq!(array, [3.0, 4.0]);
+
+        fn length(v: impl Into<Vector>) -> f64 {
+            v.into().magnitude()
+        }
+        assert_eq!(length((3.0, 4.0)), 5.0);
+        assert_eq!(length([6.0, 8.0]), 10.0);
+    }
+
+    #[test]
+    fn test_component_iteration() {
+        let v = Vector::new(3.0, 4.0);
+        assert_eq!(v.components(), [3.0, 4.0]);
+        assert_eq!(v.into_iter().collect::<Vec<f64>>(), vec![3.0, 4.0]);
+
+        let mut total = 0.0;
+        for component in v {
+            total += component;
+        }
+        assert_eq!(total, 7.0);
+        assert_eq!(Vector::from(v.components()), v);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam_round_trip() {
+        let v = Vector::new(1.5, -2.25);
+        let g: glam::Vec2 = v.into();
+        assert_eq!(g, glam::Vec2::new(1.5, -2.25));
+        assert_eq!(Vector::from(g), v);
+
+        // f64 precision beyond f32 is lost on the way to glam
+        let precise = Vector::new(0.1, 0.2);
+        assert_ne!(Vector::from(glam::Vec2::from(precise)), precise);
+        assert!(Vector::from(glam::Vec2::from(precise)).approx_eq(precise, 1e-7));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra_round_trip() {
+        let v = Vector::new(0.1, -2.0 / 3.0);
+        let n: nalgebra::Vector2<f64> = v.into();
+        assert_eq!(n, nalgebra::Vector2::new(0.1, -2.0 / 3.0));
+        assert_eq!(Vector::from(n), v);
+    }
+
+    #[test]
+    fn test_integer_vector() {
+        let v1: Vector<i32> = Vector::new(1, 2);
+        let v2: Vector<i32> = Vector::new(3, 4);
+
+        assert_eq!(v1 + v2, Vector::new(4, 6));
+        assert_eq!(v1 * 3, Vector::new(3, 6));
+        assert_eq!(v1.dot(v2), 11);
+        assert_eq!(v1.cross(v2), -2);
+        assert_eq!(v1.magnitude_squared(), 5);
+        assert_eq!(v1.component_mul(v2), Vector::new(3, 8));
+        assert_eq!(v2.component_div(v1).unwrap(), Vector::new(3, 2));
+        assert!(v1.component_div(Vector::default()).is_err());
+        assert_eq!(v1.to_string(), "Vector(1, 2)");
+
+        let v3: Vector<i32> = Vector::new(5, -1);
+        assert_eq!(v1.min_components(v3), Vector::new(1, -1));
+        assert_eq!(v1.max_components(v3), Vector::new(5, 2));
+        assert_eq!(v3.clamp(0, 3), Vector::new(3, 0));
+    }
+
+    #[test]
+    fn test_f32_vector() {
+        let v: Vector<f32> = Vector::new(3.0, 4.0);
+        assert_eq!(v.magnitude(), 5.0f32);
+        assert!(v.normalized().unwrap().approx_eq(Vector::new(0.6, 0.8), 1e-6));
+        assert_eq!(Vector::<f32>::default(), Vector::new(0.0, 0.0));
+        assert_eq!(2.0f32 * v, Vector::new(6.0, 8.0));
+    }
+
+    #[test]
+    fn test_default_component_type_is_f64() {
+        let v = Vector::new(3.0, 4.0);
+        assert_eq!(v.magnitude(), 5.0);
+
+        let explicit: Vector<f64> = Vector::zero();
+        assert_eq!(explicit, Vector::default());
+    }
+
+    #[test]
+    fn test_serde_array_representation() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Point {
+            #[serde(with = "as_array")]
+            position: Vector,
+        }
+
+        let point = Point { position: Vector::new(3.0, 4.0) };
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, r#"{"position":[3.0,4.0]}"#);
+        assert_eq!(serde_json::from_str::<Point>(&json).unwrap(), point);
+
+        let parsed: Vector =
+            as_array::deserialize(&mut serde_json::Deserializer::from_str("[3.0, 4.0]")).unwrap();
+        assert_eq!(parsed, Vector::new(3.0, 4.0));
+        assert!(serde_json::from_str::<Point>(r#"{"position":[3.0]}"#).is_err());
+
+        // The default representation is unchanged
+        assert_eq!(
+            serde_json::to_string(&Vector::new(3.0, 4.0)).unwrap(),
+            r#"{"x":3.0,"y":4.0}"#
+        );
+    }
+
+    #[test]
+    fn test_csv_row() {
+        let v: Vector = Vector::from_csv_row("3,4").unwrap();
+        assert_eq!(v, Vector::new(3.0, 4.0));
+        assert_eq!(v.to_csv_row(), "3,4");
+        assert_eq!(Vector::from_csv_row(" 0.1 , -2.5\n"), Ok(Vector::new(0.1, -2.5)));
+
+        let third = Vector::new(1.0 / 3.0, 1e-300);
+        assert_eq!(Vector::from_csv_row(&third.to_csv_row()), Ok(third));
+
+        assert_eq!(
+            Vector::<f64>::from_csv_row("3,4,5"),
+            Err(VectorError::InvalidOperation("Expected 2 CSV columns, got 3".to_string()))
+        );
+        assert_eq!(
+            Vector::<f64>::from_csv_row("abc,4"),
+            Err(VectorError::InvalidOperation("Invalid CSV field: \"abc\"".to_string()))
+        );
+        assert!(Vector::<f64>::from_csv_row("3").is_err());
+    }
+
+    #[test]
+    fn test_le_bytes() {
+        let bytes = Vector::new(1.0, 2.0).to_le_bytes();
+        assert_eq!(
+            bytes,
+            [0, 0, 0, 0, 0, 0, 0xf0, 0x3f, 0, 0, 0, 0, 0, 0, 0, 0x40]
+        );
+        assert_eq!(Vector::from_le_bytes(bytes), Vector::new(1.0, 2.0));
+
+        let v = Vector::new(-0.0, f64::MIN_POSITIVE / 3.0);
+        let round_trip = Vector::from_le_bytes(v.to_le_bytes());
+        assert_eq!(round_trip.x.to_bits(), v.x.to_bits());
+        assert_eq!(round_trip.y.to_bits(), v.y.to_bits());
+    }
+
+    #[test]
+    fn test_approx_eq_default() {
+        let v = Vector::new(1.0, 2.0);
+        let nudged = v + Vector::new(1e-12, -1e-12);
+
+        assert!(v.approx_eq_default(nudged));
+        assert!(!v.approx_eq_within(nudged, 1e-13));
+        assert!(!v.approx_eq_default(Vector::new(1.0, 2.001)));
+
+        assert!(0.1_f64.approx_eq_default(0.1 + 1e-12));
+        assert!(!0.1_f64.approx_eq_within(0.1 + 1e-12, 1e-13));
+    }
+
+    #[test]
+    fn test_hashable_vector() {
+        use std::collections::HashSet;
+
+        let points = [
+            Vector::new(1.0, 2.0),
+            Vector::new(3.0, 4.0),
+            Vector::new(1.0, 2.0),
+            Vector::new(3.0, 4.0),
+            Vector::new(5.0, 6.0),
+        ];
+        let unique: HashSet<HashableVector> = points.iter().copied().map(HashableVector).collect();
+        assert_eq!(unique.len(), 3);
+        assert!(unique.contains(&HashableVector(Vector::new(5.0, 6.0))));
+
+        // Bitwise semantics
+        let nan = HashableVector(Vector::new(f64::NAN, 0.0));
+        assert_eq!(nan, nan);
+        assert_ne!(HashableVector(Vector::new(0.0, 0.0)), HashableVector(Vector::new(-0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_orientation() {
+        let a = Vector::new(0.0, 0.0);
+        let b = Vector::new(1.0, 0.0);
+
+        assert_eq!(orientation(a, b, Vector::new(1.0, 1.0)), Orientation::CounterClockwise);
+        assert_eq!(orientation(a, b, Vector::new(1.0, -1.0)), Orientation::Clockwise);
+        assert_eq!(orientation(a, b, Vector::new(3.0, 0.0)), Orientation::Collinear);
+
+        // Rounding error on a long diagonal still counts as collinear
+        let c = Vector::new(0.1, 0.1) * 3.0;
+        assert_eq!(orientation(a, Vector::new(0.3, 0.3), c), Orientation::Collinear);
+        assert_eq!(orientation(a, a, b), Orientation::Collinear);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let corners = [
+            Vector::new(0.0, 0.0),
+            Vector::new(1.0, 0.0),
+            Vector::new(1.0, 1.0),
+            Vector::new(0.0, 1.0),
+        ];
+        assert_eq!(centroid(&corners), Some(Vector::new(0.5, 0.5)));
+        assert_eq!(centroid(&[Vector::new(-2.0, 7.0)]), Some(Vector::new(-2.0, 7.0)));
+        assert_eq!(centroid::<f64>(&[]), None);
+    }
+
+    #[test]
+    fn test_slices_approx_eq() {
+        let path = [Vector::zero(), Vector::new(1.0, 1.0), Vector::new(2.0, 0.5)];
+        let nudged: Vec<Vector> = path.iter().map(|&v| v + Vector::new(1e-12, 0.0)).collect();
+
+        assert!(slices_approx_eq(&path, &nudged, EPSILON));
+        assert!(!slices_approx_eq(&path, &nudged[..2], EPSILON));
+
+        let mut diverging = nudged.clone();
+        diverging[1].y += 0.1;
+        assert!(!slices_approx_eq(&path, &diverging, EPSILON));
+        assert!(slices_approx_eq::<f64>(&[], &[], EPSILON));
+    }
+
+    #[test]
+    fn test_areas() {
+        let square = [
+            Vector::new(0.0, 0.0),
+            Vector::new(1.0, 0.0),
+            Vector::new(1.0, 1.0),
+            Vector::new(0.0, 1.0),
+        ];
+        assert_eq!(polygon_area(&square), 1.0);
+
+        let mut clockwise = square;
+        clockwise.reverse();
+        assert_eq!(polygon_area(&clockwise), 1.0);
+
+        let (a, b, c) = (Vector::new(0.0, 0.0), Vector::new(1.0, 0.0), Vector::new(0.0, 1.0));
+        assert_eq!(triangle_area(a, b, c), 0.5);
+        assert_eq!(triangle_area(a, c, b), 0.5);
+        assert_eq!(polygon_area(&[a, b, c]), 0.5);
+
+        assert_eq!(triangle_area(a, b, Vector::new(3.0, 0.0)), 0.0);
+        assert_eq!(polygon_area(&[a, b]), 0.0);
+        assert_eq!(polygon_area::<f64>(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let points = [
+            Vector::new(2.0, 2.0),
+            Vector::new(1.0, 1.0),
+            Vector::new(0.0, 2.0),
+            Vector::new(2.0, 0.0),
+            Vector::new(0.0, 0.0),
+            Vector::new(1.0, 0.0),
+            Vector::new(0.0, 0.0),
+        ];
+        assert_eq!(
+            convex_hull(&points),
+            vec![
+                Vector::new(0.0, 0.0),
+                Vector::new(2.0, 0.0),
+                Vector::new(2.0, 2.0),
+                Vector::new(0.0, 2.0),
+            ]
+        );
+
+        let collinear = [Vector::new(1.0, 1.0), Vector::new(3.0, 3.0), Vector::new(2.0, 2.0)];
+        assert_eq!(
+            convex_hull(&collinear),
+            vec![Vector::new(1.0, 1.0), Vector::new(3.0, 3.0)]
+        );
+
+        assert!(convex_hull::<f64>(&[]).is_empty());
+        assert_eq!(convex_hull(&[Vector::unit_x(), Vector::unit_x()]), vec![Vector::unit_x()]);
+    }
+
+    #[test]
+    fn test_vector3_arithmetic() {
+        let v1 = Vector3::new(1.0, 2.0, 3.0);
+        let v2 = Vector3::new(4.0, 5.0, 6.0);
+
+        assert_eq!(v1 + v2, Vector3::new(5.0, 7.0, 9.0));
+        assert_eq!(v2 - v1, Vector3::new(3.0, 3.0, 3.0));
+        assert_eq!(v1 * 2.0, Vector3::new(2.0, 4.0, 6.0));
+        assert_eq!(-v1, Vector3::new(-1.0, -2.0, -3.0));
+        assert_eq!(v1.to_string(), "Vector3(1, 2, 3)");
+    }
+
+    #[test]
+    fn test_vector3_products() {
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+
+        assert_eq!(x.dot(y), 0.0);
+        assert_eq!(x.cross(y), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(y.cross(x), Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(Vector3::new(1.0, 2.0, 3.0).dot(Vector3::new(4.0, 5.0, 6.0)), 32.0);
+    }
+
+    #[test]
+    fn test_vector3_magnitude_and_normalization() {
+        let v = Vector3::new(2.0, 3.0, 6.0);
+        assert_eq!(v.magnitude(), 7.0);
+        assert!((v.normalized().unwrap().magnitude() - 1.0).abs() < EPSILON);
+        assert_eq!(
+            Vector3::zero().normalized(),
+            Err(VectorError::ZeroVectorNormalization)
+        );
+        assert_eq!(Vector3::zero().distance_to(v), 7.0);
+    }
+
+    #[test]
+    fn test_vector3_serde_round_trip() {
+        let v = Vector3::new(1.5, -2.0, 3.25);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"x":1.5,"y":-2.0,"z":3.25}"#);
+        assert_eq!(serde_json::from_str::<Vector3>(&json).unwrap(), v);
+    }
 }
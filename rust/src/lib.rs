@@ -2,13 +2,37 @@
 //! 
 //! This crate provides high-performance Rust implementations of the data structures
 //! and algorithms from Luciano Ramalho's "Fluent Python" book.
+//!
+//! # `no_std` support
+//!
+//! The `std` feature is enabled by default. Building with
+//! `--no-default-features` makes the crate `#![no_std]` (it still needs
+//! `alloc`): the `Vector`, `Vector3` and `Matrix2` math stays available, with `libm`
+//! providing `sqrt`, `acos`, `sin` and `cos`. `Display` works through
+//! `core::fmt`, while `to_string`, `VectorError` messages and `dot_batch` rely
+//! on `alloc`. The card types (`french_deck`, `poker`) and
+//! `Vector::random_unit` need `rand` and remain std-only.
+//!
+//! CI checks the `no_std` build against a bare-metal target with:
+//!
+//! ```text
+//! cargo build --no-default-features --target thumbv7em-none-eabihf
+//! ```
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub mod ch01_data_model;
 
 pub use ch01_data_model::*;
 
+#[cfg(feature = "python")]
+pub mod python;
+
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "std")]
     use super::*;
 
     #[test]
@@ -16,6 +40,7 @@ mod tests {
         assert_eq!(2 + 2, 4);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_basic_integration() {
         let deck = FrenchDeck::new();
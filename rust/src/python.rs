@@ -0,0 +1,168 @@
+//! Python bindings via PyO3, enabled by the `python` feature
+//!
+//! Exposes `Vector`, `Card` and `FrenchDeck` to Python so the ports can be
+//! compared side by side with the book's originals. Build and install the
+//! extension module with `maturin develop` or `pip install ./rust`, then
+//! import it as `fluent_python_rs`.
+//!
+//! maturin builds with the `extension-module` feature, which leaves libpython
+//! unlinked as extension modules require. Plain `python` links it, so
+//! `cargo test --features python` runs the tests below in an embedded
+//! interpreter.
+
+use crate::ch01_data_model::{Card, FrenchDeck, Vector};
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+
+/// Python wrapper around `Vector`
+#[pyclass(name = "Vector")]
+#[derive(Debug, Clone, Copy)]
+pub struct PyVector {
+    inner: Vector,
+}
+
+#[pymethods]
+impl PyVector {
+    #[new]
+    #[pyo3(signature = (x = 0.0, y = 0.0))]
+    fn new(x: f64, y: f64) -> Self {
+        PyVector { inner: Vector::new(x, y) }
+    }
+
+    #[getter]
+    fn x(&self) -> f64 {
+        self.inner.x
+    }
+
+    #[getter]
+    fn y(&self) -> f64 {
+        self.inner.y
+    }
+
+    fn __add__(&self, other: &PyVector) -> PyVector {
+        PyVector { inner: self.inner + other.inner }
+    }
+
+    fn __mul__(&self, scalar: f64) -> PyVector {
+        PyVector { inner: self.inner * scalar }
+    }
+
+    fn __abs__(&self) -> f64 {
+        self.inner.magnitude()
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.inner.is_zero()
+    }
+
+    fn __eq__(&self, other: &PyVector) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Vector({:?}, {:?})", self.inner.x, self.inner.y)
+    }
+}
+
+/// Python wrapper around `Card`, mirroring the book's `Card` namedtuple
+#[pyclass(name = "Card", frozen)]
+#[derive(Debug, Clone, Copy)]
+pub struct PyCard {
+    inner: Card,
+}
+
+#[pymethods]
+impl PyCard {
+    #[getter]
+    fn rank(&self) -> &'static str {
+        self.inner.rank.short_name()
+    }
+
+    #[getter]
+    fn suit(&self) -> String {
+        self.inner.suit.to_string().to_lowercase()
+    }
+
+    fn __eq__(&self, other: &PyCard) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Card(rank='{}', suit='{}')", self.rank(), self.suit())
+    }
+}
+
+/// Python wrapper around `FrenchDeck`
+#[pyclass(name = "FrenchDeck")]
+#[derive(Debug, Clone, Default)]
+pub struct PyFrenchDeck {
+    inner: FrenchDeck,
+}
+
+#[pymethods]
+impl PyFrenchDeck {
+    #[new]
+    fn new() -> Self {
+        PyFrenchDeck::default()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Index like a Python list: negative indices count from the end
+    fn __getitem__(&self, index: isize) -> PyResult<PyCard> {
+        self.inner
+            .get_wrapped(index)
+            .map(|&card| PyCard { inner: card })
+            .ok_or_else(|| PyIndexError::new_err("deck index out of range"))
+    }
+}
+
+/// The `fluent_python_rs` Python module
+#[pymodule]
+fn fluent_python_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyVector>()?;
+    m.add_class::<PyCard>()?;
+    m.add_class::<PyFrenchDeck>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::IntoPyDict;
+
+    #[test]
+    fn test_python_vector() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let locals = [("Vector", py.get_type::<PyVector>())].into_py_dict(py).unwrap();
+            pyo3::py_run!(py, *locals, r#"
+                v1, v2 = Vector(2, 4), Vector(1, -1)
+                assert repr(v1 + v2) == "Vector(3.0, 3.0)"
+                assert abs(Vector(3, 4)) == 5.0
+                assert v1 * 0.5 == Vector(1.0, 2.0)
+                assert not Vector()
+            "#);
+        });
+    }
+
+    #[test]
+    fn test_python_french_deck() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let deck = Py::new(py, PyFrenchDeck::new()).unwrap();
+            pyo3::py_run!(py, deck, r#"
+                assert len(deck) == 52
+                assert repr(deck[0]) == "Card(rank='2', suit='spades')"
+                assert deck[-1].rank == 'A' and deck[-1].suit == 'clubs'
+                try:
+                    deck[52]
+                    raise AssertionError("expected IndexError")
+                except IndexError:
+                    pass
+            "#);
+        });
+    }
+}